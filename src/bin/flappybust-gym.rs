@@ -0,0 +1,61 @@
+//! Line based protocol to train agents from another language.
+//!
+//! Each request is a line on stdin, each response a line on stdout:
+//!
+//! ```text
+//! reset [seed]  ->  obs <bird_y> <velocity> <rotation> <gap_top> <gap_bottom> <gap_distance>
+//! step <0|1>    ->  obs <...> reward <reward> done <0|1>
+//! quit
+//! ```
+//!
+//! Invalid requests are answered with `error <reason>`.
+
+use flappybust::game::gym::{components::Observation, Gym};
+use std::io::{self, BufRead, Write};
+
+fn main() -> io::Result<()> {
+    let mut gym = Gym::new();
+    let mut stdout = io::stdout().lock();
+
+    for line in io::stdin().lock().lines() {
+        let line = line?;
+        let mut words = line.split_whitespace();
+
+        let response = match (words.next(), words.next()) {
+            (Some("reset"), None) => format_observation(&gym.reset(None)),
+            (Some("reset"), Some(seed)) => match seed.parse() {
+                Ok(seed) => format_observation(&gym.reset(Some(seed))),
+                Err(err) => format!("error invalid seed {seed}: {err}"),
+            },
+            (Some("step"), Some(action @ ("0" | "1"))) => {
+                let step = gym.step(action == "1");
+
+                format!(
+                    "{} reward {} done {}",
+                    format_observation(&step.observation),
+                    step.reward,
+                    u8::from(step.done)
+                )
+            }
+            (Some("step"), _) => "error step expects 0 or 1".to_string(),
+            (Some("quit"), _) => break,
+            (None, _) => continue,
+            (Some(request), _) => format!("error unknown request {request}"),
+        };
+
+        writeln!(stdout, "{response}")?;
+        stdout.flush()?;
+    }
+
+    Ok(())
+}
+
+fn format_observation(observation: &Observation) -> String {
+    let values: Vec<String> = observation
+        .values()
+        .iter()
+        .map(ToString::to_string)
+        .collect();
+
+    format!("obs {}", values.join(" "))
+}
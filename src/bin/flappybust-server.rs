@@ -0,0 +1,134 @@
+//! Reference score server.
+//!
+//! Keeps the global leaderboard in a file and only ranks the runs
+//! that reach their score when played again headlessly,
+//! on worker threads so a long replay does not hold up the other requests:
+//!
+//! ```text
+//! flappybust-server [--address 127.0.0.1:8000] [--data scores.ron]
+//! ```
+
+use flappybust::game::{leaderboard::components::Leaderboard, online::server};
+use std::{
+    fs,
+    io::Read,
+    num::NonZeroUsize,
+    process,
+    sync::{
+        mpsc::{self, TrySendError},
+        Arc, Mutex, PoisonError,
+    },
+    thread,
+};
+use tiny_http::{Header, Request, Response, Server};
+
+const DEFAULT_ADDRESS: &str = "127.0.0.1:8000";
+const DEFAULT_DATA: &str = "scores.ron";
+/// Largest request body read, an hour long replay fits easily.
+const MAX_BODY: u64 = 1 << 20;
+/// Requests waiting for a worker, past which the server answers `503`.
+const MAX_QUEUED: usize = 64;
+
+fn main() {
+    let mut address = DEFAULT_ADDRESS.to_string();
+    let mut data = DEFAULT_DATA.to_string();
+    let mut args = std::env::args().skip(1);
+
+    while let Some(arg) = args.next() {
+        match (arg.as_str(), args.next()) {
+            ("--address", Some(value)) => address = value,
+            ("--data", Some(value)) => data = value,
+            _ => {
+                eprintln!(
+                    "Usage: flappybust-server [--address {DEFAULT_ADDRESS}] [--data {DEFAULT_DATA}]"
+                );
+                process::exit(2);
+            }
+        }
+    }
+
+    let leaderboard: Leaderboard = match fs::read_to_string(&data) {
+        Ok(contents) => ron::from_str(&contents).unwrap_or_else(|err| {
+            eprintln!("Failed to read {data}: {err}");
+            process::exit(1);
+        }),
+        Err(_) => Leaderboard::default(),
+    };
+    let leaderboard = Arc::new(Mutex::new(leaderboard));
+
+    let http = Server::http(&address).unwrap_or_else(|err| {
+        eprintln!("Failed to listen on {address}: {err}");
+        process::exit(1);
+    });
+    // Lets the web build submit runs from another origin.
+    let cors =
+        Header::from_bytes("Access-Control-Allow-Origin", "*").expect("Header must be valid.");
+
+    let (sender, receiver) = mpsc::sync_channel::<Request>(MAX_QUEUED);
+    let receiver = Arc::new(Mutex::new(receiver));
+    let workers = thread::available_parallelism().map_or(1, NonZeroUsize::get);
+
+    for _ in 0..workers {
+        let receiver = receiver.clone();
+        let leaderboard = leaderboard.clone();
+        let data = data.clone();
+        let cors = cors.clone();
+
+        thread::spawn(move || loop {
+            let next = receiver
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner)
+                .recv();
+            let Ok(request) = next else {
+                return;
+            };
+
+            handle(request, &leaderboard, &data, &cors);
+        });
+    }
+
+    println!("Listening on http://{address} with {workers} workers");
+
+    for request in http.incoming_requests() {
+        if let Err(TrySendError::Full(request) | TrySendError::Disconnected(request)) =
+            sender.try_send(request)
+        {
+            println!("{} {} 503", request.method(), request.url());
+            answer(request, 503, "busy".to_string(), &cors);
+        }
+    }
+}
+
+fn handle(mut request: Request, leaderboard: &Mutex<Leaderboard>, data: &str, cors: &Header) {
+    let mut body = String::new();
+    let (status, text) = match request.as_reader().take(MAX_BODY).read_to_string(&mut body) {
+        Ok(_) => server::respond(request.method().as_str(), request.url(), &body, leaderboard),
+        Err(err) => (400, err.to_string()),
+    };
+
+    println!("{} {} {status}", request.method(), request.url());
+
+    if status == 201 {
+        // Kept locked while writing, so an older table never overwrites a newer one.
+        let leaderboard = leaderboard.lock().unwrap_or_else(PoisonError::into_inner);
+        let result = ron::ser::to_string_pretty(&*leaderboard, Default::default())
+            .map_err(|err| err.to_string())
+            .and_then(|contents| fs::write(data, contents).map_err(|err| err.to_string()));
+
+        if let Err(err) = result {
+            eprintln!("Failed to write {data}: {err}");
+        }
+    }
+
+    answer(request, status, text, cors);
+}
+
+fn answer(request: Request, status: u16, text: String, cors: &Header) {
+    let response = Response::from_string(text)
+        .with_status_code(status)
+        .with_header(cors.clone());
+
+    if let Err(err) = request.respond(response) {
+        eprintln!("Failed to answer: {err}");
+    }
+}
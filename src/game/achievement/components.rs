@@ -0,0 +1,99 @@
+use crate::game::{game_over::components::MedalType, DateTime};
+use bevy::{prelude::*, utils::HashSet};
+use serde::Deserialize;
+
+/// What must be done to unlock an achievement.
+#[derive(Deserialize, Clone, Copy, PartialEq, Debug)]
+pub enum Goal {
+    /// Score in a single run.
+    Score(usize),
+    /// Score in a single run at the given time of day.
+    ScoreAt(DateTime, usize),
+    /// Pipes passed over every run.
+    Pipes(usize),
+    /// Runs finished.
+    Games(usize),
+    /// Coins collected in a single run.
+    Coins(usize),
+    /// Medal earned, or a better one.
+    Medal(MedalType),
+    DieWithoutFlapping,
+}
+
+impl Goal {
+    pub fn reached(self, progress: &Progress) -> bool {
+        match self {
+            Goal::Score(score) => progress.score >= score,
+            Goal::ScoreAt(datetime, score) => {
+                progress.datetime == datetime && progress.score >= score
+            }
+            Goal::Pipes(pipes) => progress.pipes >= pipes,
+            Goal::Games(games) => progress.games >= games,
+            Goal::Coins(coins) => progress.coins >= coins,
+            Goal::Medal(medal) => progress.medal.is_some_and(|earned| earned >= medal),
+            Goal::DieWithoutFlapping => progress.dead && progress.flaps == 0,
+        }
+    }
+}
+
+#[derive(Deserialize, Clone, Debug)]
+pub struct Achievement {
+    /// Name in the save file, must never change.
+    pub id: String,
+    pub name: String,
+    pub description: String,
+    pub goal: Goal,
+}
+
+/// Every achievement, read from `assets/achievements.ron`.
+#[derive(Asset, Resource, TypePath, Deserialize, Clone)]
+#[serde(transparent)]
+pub struct Achievements(pub Vec<Achievement>);
+
+/// The achievements built into the game, none if they don't parse.
+impl Default for Achievements {
+    fn default() -> Self {
+        ron::from_str(include_str!("../../../assets/achievements.ron")).unwrap_or_else(|err| {
+            error!("Ignoring the built-in achievements: {err}");
+            Achievements(Vec::new())
+        })
+    }
+}
+
+/// Ids of the achievements unlocked by the player.
+#[derive(Resource, Default, Clone)]
+pub struct Unlocked(pub HashSet<String>);
+
+/// Where the player stands, checked against every goal.
+#[derive(Resource, Default, Clone, Copy, Debug)]
+pub struct Progress {
+    pub datetime: DateTime,
+    pub score: usize,
+    pub coins: usize,
+    pub flaps: usize,
+    /// Including the ones of previous runs.
+    pub pipes: usize,
+    /// Including the current run once it is over.
+    pub games: usize,
+    pub medal: Option<MedalType>,
+    pub dead: bool,
+}
+
+#[derive(Component)]
+pub(super) struct Toast(pub Timer);
+
+#[derive(Component)]
+pub(super) struct GalleryScreen;
+
+/// Text of the gallery filled with the achievements of the current page.
+#[derive(Component, Clone, Copy)]
+pub(super) enum GalleryText {
+    Counter,
+    /// Name of the achievement on this row of the page.
+    Name(usize),
+    Description(usize),
+}
+
+/// Page of the gallery being browsed.
+#[derive(Resource, Default)]
+pub(super) struct GalleryPage(pub usize);
@@ -0,0 +1,6 @@
+use bevy::ecs::event::Event;
+
+/// Event triggers when the player unlocks an achievement,
+/// with its index in `Achievements`.
+#[derive(Event)]
+pub struct AchievementUnlocked(pub usize);
@@ -0,0 +1,469 @@
+automod::dir!(pub "src/game/achievement");
+
+use super::{
+    audio::events::AudioEvent,
+    autopilot,
+    bird::events::FlapEvent,
+    button::{
+        components::{BackButton, SpriteButton},
+        spawn_labelled, Back, ButtonSystem,
+    },
+    coin::components::Wallet,
+    collision::events::CollisionEvent,
+    config::components::GameConfig,
+    controls::components::Controls,
+    game_over::components::MedalType,
+    menu::components::SettingsReturn,
+    replay::components::Playback,
+    save::components::Statistics,
+    score::components::Score,
+    AudioAssets, DateTime, FontAssets, Simulation,
+};
+use crate::{despawn_recursive, ternary, BasicMath, GameState, SCREEN_HEIGHT, SCREEN_WIDTH};
+use bevy::{
+    asset::{io::Reader, AssetLoader, AsyncReadExt, LoadContext},
+    prelude::*,
+    utils::BoxedFuture,
+};
+use components::*;
+use events::*;
+
+/// Achievement logic.
+pub struct AchievementPlugin;
+
+impl AchievementPlugin {
+    /// Achievements shown on each page of the gallery.
+    const PAGE_SIZE: usize = 6;
+    /// How long an unlock stays on screen, in seconds.
+    const TOAST_DURATION: f32 = 3f32;
+}
+
+impl Plugin for AchievementPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<AchievementUnlocked>()
+            .init_resource::<Achievements>()
+            .init_resource::<Unlocked>()
+            .init_resource::<Progress>()
+            .init_resource::<Statistics>()
+            .init_resource::<GalleryPage>()
+            .add_systems(
+                OnTransition {
+                    from: GameState::Ready,
+                    to: GameState::Playing,
+                },
+                start_run,
+            )
+            .add_systems(
+                FixedUpdate,
+                (observe, unlock).chain().after(Simulation::Scoring).run_if(
+                    in_state(GameState::Playing)
+                        .and_then(not(autopilot::has_flown))
+                        .and_then(not(resource_exists::<Playback>)),
+                ),
+            )
+            .add_systems(
+                Update,
+                (
+                    (show_toast, hide_toasts),
+                    open_gallery.run_if(in_state(GameState::Ready)),
+                    (browse.after(ButtonSystem), display_page)
+                        .chain()
+                        .run_if(in_state(GameState::Gallery)),
+                ),
+            )
+            .add_systems(OnEnter(GameState::Gallery), spawn_gallery)
+            .add_systems(
+                OnExit(GameState::Gallery),
+                despawn_recursive::<GalleryScreen>,
+            )
+            .add_systems(OnExit(GameState::Over), despawn_recursive::<Toast>)
+            .add_systems(
+                OnTransition {
+                    from: GameState::Paused,
+                    to: GameState::Ready,
+                },
+                despawn_recursive::<Toast>,
+            );
+    }
+}
+
+/// Achievement data logic.
+///
+/// The achievements start as the ones built into the game,
+/// then follow `assets/achievements.ron` each time it is loaded.
+/// A file that fails to load is logged and the last achievements are kept.
+pub struct AchievementDataPlugin;
+
+impl Plugin for AchievementDataPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_asset::<Achievements>()
+            .init_asset_loader::<AchievementsLoader>()
+            .add_systems(Startup, load)
+            .add_systems(Update, apply);
+    }
+}
+
+#[derive(Resource)]
+struct AchievementsHandle(Handle<Achievements>);
+
+#[derive(Default)]
+struct AchievementsLoader;
+
+impl AssetLoader for AchievementsLoader {
+    type Asset = Achievements;
+    type Settings = ();
+    type Error = String;
+
+    fn load<'a>(
+        &'a self,
+        reader: &'a mut Reader,
+        _settings: &'a (),
+        _load_context: &'a mut LoadContext,
+    ) -> BoxedFuture<'a, Result<Achievements, String>> {
+        Box::pin(async move {
+            let mut bytes = Vec::new();
+
+            reader
+                .read_to_end(&mut bytes)
+                .await
+                .map_err(|err| err.to_string())?;
+
+            ron::de::from_bytes(&bytes).map_err(|err| err.to_string())
+        })
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["ron"]
+    }
+}
+
+fn load(mut commands: Commands, asset_server: Res<AssetServer>) {
+    commands.insert_resource(AchievementsHandle(asset_server.load("achievements.ron")));
+}
+
+fn apply(
+    mut asset_event: EventReader<AssetEvent<Achievements>>,
+    handle: Res<AchievementsHandle>,
+    loaded: Res<Assets<Achievements>>,
+    mut achievements: ResMut<Achievements>,
+) {
+    for event in asset_event.read() {
+        if event.is_loaded_with_dependencies(&handle.0) || event.is_modified(&handle.0) {
+            if let Some(loaded) = loaded.get(&handle.0) {
+                info!("Applying achievements");
+                *achievements = loaded.clone();
+            }
+        }
+    }
+}
+
+fn start_run(datetime: Res<DateTime>, mut progress: ResMut<Progress>) {
+    *progress = Progress {
+        datetime: *datetime,
+        ..default()
+    };
+}
+
+fn observe(
+    mut flap_event: EventReader<FlapEvent>,
+    mut collision_event: EventReader<CollisionEvent>,
+    score: Res<Score>,
+    wallet: Res<Wallet>,
+    statistics: Res<Statistics>,
+    config: Res<GameConfig>,
+    mut progress: ResMut<Progress>,
+) {
+    progress.flaps += flap_event.read().count();
+
+    // A pipe ends the run as surely as the ground, which the bird falls to afterwards.
+    if collision_event.read().count() > 0 {
+        progress.dead = true;
+    }
+
+    progress.score = score.current;
+    progress.coins = wallet.collected;
+    progress.pipes = statistics.pipes_passed + score.current;
+    progress.games = statistics.games_played + usize::from(progress.dead);
+    progress.medal = progress
+        .dead
+        .then(|| MedalType::from_score(score.current, &config.medals))
+        .flatten();
+}
+
+fn unlock(
+    progress: Res<Progress>,
+    achievements: Res<Achievements>,
+    mut unlocked: ResMut<Unlocked>,
+    mut unlocked_event: EventWriter<AchievementUnlocked>,
+) {
+    for (index, achievement) in achievements.0.iter().enumerate() {
+        if !unlocked.0.contains(&achievement.id) && achievement.goal.reached(&progress) {
+            unlocked.0.insert(achievement.id.clone());
+            unlocked_event.send(AchievementUnlocked(index));
+        }
+    }
+}
+
+fn show_toast(
+    mut commands: Commands,
+    mut unlocked_event: EventReader<AchievementUnlocked>,
+    achievements: Res<Achievements>,
+    toasts: Query<(), With<Toast>>,
+    font_assets: Res<FontAssets>,
+    audio_assets: Res<AudioAssets>,
+    mut audio_event: EventWriter<AudioEvent>,
+) {
+    let text_style = TextStyle {
+        font: font_assets.teko_bold.clone(),
+        font_size: 16f32,
+        ..default()
+    };
+
+    // Stack the toasts below the current score.
+    for (i, AchievementUnlocked(index)) in unlocked_event.read().enumerate() {
+        let Some(achievement) = achievements.0.get(*index) else {
+            continue;
+        };
+        let y = SCREEN_HEIGHT.half() - 100f32 - 52f32 * (toasts.iter().len() + i) as f32;
+
+        commands
+            .spawn((
+                SpriteBundle {
+                    transform: Transform::from_xyz(0f32, y, 0.95),
+                    sprite: Sprite {
+                        color: Color::rgba(0f32, 0f32, 0f32, 0.75),
+                        custom_size: Some(Vec2::new(SCREEN_WIDTH - 48f32, 44f32)),
+                        ..default()
+                    },
+                    ..default()
+                },
+                Toast(Timer::from_seconds(
+                    AchievementPlugin::TOAST_DURATION,
+                    TimerMode::Once,
+                )),
+            ))
+            .with_children(|builder| {
+                builder.spawn(Text2dBundle {
+                    text: Text::from_section("ACHIEVEMENT UNLOCKED", text_style.clone()),
+                    transform: Transform::from_xyz(0f32, 10f32, 0.1),
+                    ..default()
+                });
+
+                builder.spawn(Text2dBundle {
+                    text: Text::from_section(
+                        achievement.name.clone(),
+                        TextStyle {
+                            font_size: 24f32,
+                            ..text_style.clone()
+                        },
+                    ),
+                    transform: Transform::from_xyz(0f32, -8f32, 0.1),
+                    ..default()
+                });
+            });
+
+        audio_event.send(AudioEvent::sfx(&audio_assets.ding));
+    }
+}
+
+fn hide_toasts(mut commands: Commands, time: Res<Time>, mut toasts: Query<(Entity, &mut Toast)>) {
+    for (entity, mut toast) in &mut toasts {
+        if toast.0.tick(time.delta()).finished() {
+            commands.entity(entity).despawn_recursive();
+        }
+    }
+}
+
+fn open_gallery(
+    controls: Res<Controls>,
+    mut settings_return: ResMut<SettingsReturn>,
+    mut next_state: ResMut<NextState<GameState>>,
+) {
+    if controls.open_gallery {
+        settings_return.0 = GameState::Ready;
+        next_state.set(GameState::Gallery);
+    }
+}
+
+fn spawn_gallery(
+    mut commands: Commands,
+    font_assets: Res<FontAssets>,
+    mut page: ResMut<GalleryPage>,
+) {
+    page.0 = 0;
+
+    let text_style = TextStyle {
+        font: font_assets.teko_bold.clone(),
+        font_size: 24f32,
+        ..default()
+    };
+
+    let screen = commands
+        .spawn((
+            SpriteBundle {
+                transform: Transform::from_xyz(0f32, 0f32, 0.9),
+                sprite: Sprite {
+                    color: Color::rgba(0f32, 0f32, 0f32, 0.75),
+                    custom_size: Some(Vec2::new(SCREEN_WIDTH, SCREEN_HEIGHT)),
+                    ..default()
+                },
+                ..default()
+            },
+            GalleryScreen,
+        ))
+        .with_children(|builder| {
+            builder.spawn(Text2dBundle {
+                text: Text::from_section(
+                    "ACHIEVEMENTS",
+                    TextStyle {
+                        font_size: 48f32,
+                        ..text_style.clone()
+                    },
+                ),
+                transform: Transform::from_xyz(0f32, 215f32, 0.1),
+                ..default()
+            });
+
+            builder.spawn((
+                Text2dBundle {
+                    text: Text::from_section("", text_style.clone()),
+                    transform: Transform::from_xyz(0f32, 178f32, 0.1),
+                    ..default()
+                },
+                GalleryText::Counter,
+            ));
+
+            for row in 0..AchievementPlugin::PAGE_SIZE {
+                let y = 130f32 - 52f32 * row as f32;
+
+                builder.spawn((
+                    Text2dBundle {
+                        text: Text::from_section("", text_style.clone()),
+                        transform: Transform::from_xyz(0f32, y, 0.1),
+                        ..default()
+                    },
+                    GalleryText::Name(row),
+                ));
+
+                builder.spawn((
+                    Text2dBundle {
+                        text: Text::from_section(
+                            "",
+                            TextStyle {
+                                font_size: 16f32,
+                                ..text_style.clone()
+                            },
+                        ),
+                        transform: Transform::from_xyz(0f32, y - 18f32, 0.1),
+                        ..default()
+                    },
+                    GalleryText::Description(row),
+                ));
+            }
+
+            builder.spawn(Text2dBundle {
+                text: Text::from_section(
+                    "ARROWS: PAGE   ESC: BACK",
+                    TextStyle {
+                        font_size: 18f32,
+                        ..text_style.clone()
+                    },
+                ),
+                transform: Transform::from_xyz(0f32, -SCREEN_HEIGHT.half() + 24f32, 0.1),
+                ..default()
+            });
+        })
+        .id();
+
+    spawn_labelled(
+        &mut commands,
+        SpriteButton {
+            color: Color::rgb(0.98, 0.6, 0.14),
+        },
+        Vec2::new(114f32, 28f32),
+        Transform::from_xyz(0f32, -200f32, 0.1),
+        Text::from_section("BACK", text_style),
+    )
+    .insert(BackButton)
+    .set_parent(screen);
+}
+
+fn browse(
+    controls: Res<Controls>,
+    mut back: Back,
+    settings_return: Res<SettingsReturn>,
+    achievements: Res<Achievements>,
+    mut page: ResMut<GalleryPage>,
+    mut next_state: ResMut<NextState<GameState>>,
+) {
+    if back.pressed() || controls.open_gallery {
+        next_state.set(settings_return.0);
+    }
+
+    let pages = achievements
+        .0
+        .len()
+        .div_ceil(AchievementPlugin::PAGE_SIZE)
+        .max(1);
+
+    if controls.right {
+        page.0 = (page.0 + 1) % pages;
+    }
+
+    if controls.left {
+        page.0 = (page.0 + pages - 1) % pages;
+    }
+}
+
+fn display_page(
+    page: Res<GalleryPage>,
+    achievements: Res<Achievements>,
+    unlocked: Res<Unlocked>,
+    mut texts: Query<(&mut Text, &GalleryText)>,
+) {
+    if !(page.is_changed() || unlocked.is_changed()) {
+        return;
+    }
+
+    let pages = achievements
+        .0
+        .len()
+        .div_ceil(AchievementPlugin::PAGE_SIZE)
+        .max(1);
+    let on_row = |row: usize| {
+        achievements
+            .0
+            .get(page.0 * AchievementPlugin::PAGE_SIZE + row)
+            .map(|achievement| (achievement, unlocked.0.contains(&achievement.id)))
+    };
+
+    for (mut text, label) in &mut texts {
+        let section = &mut text.sections[0];
+
+        match *label {
+            GalleryText::Counter => {
+                section.value = format!(
+                    "{}/{} UNLOCKED   PAGE {}/{pages}",
+                    unlocked.0.len(),
+                    achievements.0.len(),
+                    page.0 + 1
+                );
+            }
+            GalleryText::Name(row) => {
+                section.value =
+                    on_row(row).map_or(String::new(), |(achievement, _)| achievement.name.clone());
+                section.style.color = on_row(row).map_or(Color::GRAY, |(_, unlocked)| {
+                    ternary!(unlocked, Color::GOLD, Color::GRAY)
+                });
+            }
+            GalleryText::Description(row) => {
+                section.value = on_row(row).map_or(String::new(), |(achievement, _)| {
+                    achievement.description.clone()
+                });
+                section.style.color = on_row(row).map_or(Color::GRAY, |(_, unlocked)| {
+                    ternary!(unlocked, Color::WHITE, Color::GRAY)
+                });
+            }
+        }
+    }
+}
@@ -1,4 +1,254 @@
+use crate::{game::AudioAssets, GameState};
 use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
 
 #[derive(Component)]
 pub struct AmbientMusic;
+
+/// Identifies a sound to stop or fade it later. Newer sounds have greater ids.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub struct SoundId(u64);
+
+/// Next id to give to a sound.
+#[derive(Resource, Default)]
+pub struct SoundIds(u64);
+
+impl SoundIds {
+    pub fn next_id(&mut self) -> SoundId {
+        self.0 += 1;
+        SoundId(self.0)
+    }
+}
+
+/// Sound playing, or about to once its source is loaded.
+#[derive(Component)]
+pub struct Sound(pub SoundId);
+
+/// Sound cut before it finished, despawned along with the finished ones.
+#[derive(Component)]
+pub(super) struct Stopped;
+
+/// Sound getting louder up to its volume, or quieter until it stops.
+#[derive(Component)]
+pub(super) struct Fade {
+    pub timer: Timer,
+    /// Parts of its volume the sound starts and ends the fade at.
+    pub from: f32,
+    pub to: f32,
+}
+
+impl Fade {
+    pub fn rise(seconds: f32) -> Self {
+        Fade {
+            timer: Timer::from_seconds(seconds, TimerMode::Once),
+            from: 0f32,
+            to: 1f32,
+        }
+    }
+
+    /// Fall silent from `from`, where a fade it replaces had got to.
+    pub fn fall(seconds: f32, from: f32) -> Self {
+        Fade {
+            timer: Timer::from_seconds(seconds, TimerMode::Once),
+            from,
+            to: 0f32,
+        }
+    }
+
+    /// Part of its volume the sound plays at.
+    pub fn level(&self) -> f32 {
+        self.from + (self.to - self.from) * self.timer.fraction()
+    }
+}
+
+/// Music played over a group of states.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Track {
+    /// Calm loop behind the menus.
+    Menu,
+    /// The theme, with a beat rising over it as the score grows.
+    Gameplay,
+    /// Sting played once when the bird dies.
+    Over,
+}
+
+impl Track {
+    /// Seconds for a track to fade into the next one.
+    pub const CROSSFADE: f32 = 1f32;
+
+    pub fn for_state(state: GameState) -> Option<Self> {
+        match state {
+            GameState::AssetLoading => None,
+            GameState::Playing | GameState::Paused => Some(Track::Gameplay),
+            GameState::Over => Some(Track::Over),
+            _ => Some(Track::Menu),
+        }
+    }
+
+    /// Loudest the gameplay layer gets.
+    pub const LAYER_VOLUME: f32 = 0.15;
+
+    pub fn source(self, audio_assets: &AudioAssets) -> &Handle<AudioSource> {
+        match self {
+            Track::Menu => &audio_assets.menu,
+            Track::Gameplay => &audio_assets.theme,
+            Track::Over => &audio_assets.sting,
+        }
+    }
+
+    /// Volume relative to the sound effects.
+    pub fn volume(self) -> f32 {
+        match self {
+            Track::Menu => 0.15,
+            Track::Gameplay => 0.2,
+            Track::Over => 0.25,
+        }
+    }
+
+    /// Whether the track loops until the state changes, or plays once.
+    pub fn looped(self) -> bool {
+        self != Track::Over
+    }
+
+    /// Volume of the gameplay layer at `score`,
+    /// silent under ten points then a quarter louder every ten up to forty.
+    pub fn layer_volume(score: usize) -> f32 {
+        (score / 10).min(4) as f32 / 4f32 * Self::LAYER_VOLUME
+    }
+}
+
+/// Track playing, the sound playing it and the layer played over it, if any.
+#[derive(Resource, Default)]
+pub struct MusicDirector {
+    pub(super) playing: Option<(Track, SoundId)>,
+    pub(super) layer: Option<SoundId>,
+}
+
+impl MusicDirector {
+    pub fn track(&self) -> Option<Track> {
+        self.playing.map(|(track, _)| track)
+    }
+
+    pub fn layer(&self) -> Option<SoundId> {
+        self.layer
+    }
+}
+
+/// Channel a sound plays on, its volume set on top of the master one.
+#[derive(Component, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum AudioChannel {
+    Music,
+    Sfx,
+}
+
+/// Volumes chosen by the player, kept in the save file.
+#[derive(Resource, Clone, Copy, PartialEq, Debug, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Mixer {
+    pub master: f32,
+    pub music: f32,
+    pub sfx: f32,
+    /// Whether every sound is silenced, toggled by the mute action.
+    pub muted: bool,
+}
+
+impl Default for Mixer {
+    fn default() -> Self {
+        Mixer {
+            master: 1f32,
+            music: 1f32,
+            sfx: 1f32,
+            muted: false,
+        }
+    }
+}
+
+impl Mixer {
+    /// Change of a volume on each press of an arrow.
+    pub const STEP: f32 = 0.1;
+
+    /// Volume the sounds of `channel` are played at.
+    pub fn volume(&self, channel: AudioChannel) -> f32 {
+        if self.muted {
+            return 0f32;
+        }
+
+        let level = match channel {
+            AudioChannel::Music => self.music,
+            AudioChannel::Sfx => self.sfx,
+        };
+
+        self.master * level
+    }
+
+    pub fn level(&self, slider: Slider) -> f32 {
+        match slider {
+            Slider::Master => self.master,
+            Slider::Music => self.music,
+            Slider::Sfx => self.sfx,
+        }
+    }
+
+    fn level_mut(&mut self, slider: Slider) -> &mut f32 {
+        match slider {
+            Slider::Master => &mut self.master,
+            Slider::Music => &mut self.music,
+            Slider::Sfx => &mut self.sfx,
+        }
+    }
+
+    /// Turn a volume up or down by `steps`, between silent and full.
+    pub fn nudge(&mut self, slider: Slider, steps: f32) {
+        let level = self.level_mut(slider);
+
+        // Rounded so that the steps always land on a whole percentage.
+        *level = ((*level + steps * Self::STEP).clamp(0f32, 1f32) * 100f32).round() / 100f32;
+    }
+}
+
+/// Volume shown on the audio screen.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Slider {
+    Master,
+    Music,
+    Sfx,
+}
+
+impl Slider {
+    pub const ALL: [Slider; 3] = [Self::Master, Self::Music, Self::Sfx];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Slider::Master => "MASTER",
+            Slider::Music => "MUSIC",
+            Slider::Sfx => "SOUND EFFECTS",
+        }
+    }
+}
+
+/// Row highlighted on the audio screen, the last one mutes every sound.
+#[derive(Resource, Default)]
+pub(super) struct MixerCursor(pub usize);
+
+impl MixerCursor {
+    pub const ROWS: usize = Slider::ALL.len() + 1;
+
+    pub fn slider(&self) -> Option<Slider> {
+        Slider::ALL.get(self.0).copied()
+    }
+
+    pub fn next(&mut self) {
+        self.0 = (self.0 + 1) % Self::ROWS;
+    }
+
+    pub fn prev(&mut self) {
+        self.0 = (self.0 + Self::ROWS - 1) % Self::ROWS;
+    }
+}
+
+#[derive(Component)]
+pub(super) struct MixerScreen;
+
+/// Text of an audio screen row, `None` being the mute row.
+#[derive(Component)]
+pub(super) struct MixerRow(pub Option<Slider>);
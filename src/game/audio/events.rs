@@ -1,26 +1,94 @@
-use bevy::prelude::*;
+use super::components::{AudioChannel, SoundId, SoundIds};
+use bevy::{ecs::system::SystemParam, prelude::*};
 
+/// Event triggers to play a sound.
 #[derive(Event)]
 pub struct AudioEvent {
+    /// Given when sent through `Sounds`, otherwise once it is played.
+    pub(super) id: Option<SoundId>,
     pub(super) source: Handle<AudioSource>,
+    pub(super) channel: AudioChannel,
     pub(super) looped: bool,
     pub(super) volume: f32,
+    pub(super) speed: f32,
+    /// Seconds to rise from silence to its volume.
+    pub(super) fade_in: Option<f32>,
 }
 
 impl AudioEvent {
-    pub fn new(audio: &Handle<AudioSource>, looped: bool) -> Self {
+    /// Sound effect played once.
+    pub fn sfx(audio: &Handle<AudioSource>) -> Self {
         AudioEvent {
+            id: None,
             source: audio.clone(),
-            looped,
+            channel: AudioChannel::Sfx,
+            looped: false,
             volume: 1.0,
+            speed: 1.0,
+            fade_in: None,
         }
     }
 
-    pub fn new_with_volume(audio: &Handle<AudioSource>, looped: bool, volume: f32) -> Self {
+    /// Music looped until it is stopped.
+    pub fn music(audio: &Handle<AudioSource>) -> Self {
         AudioEvent {
+            id: None,
             source: audio.clone(),
-            looped,
-            volume,
+            channel: AudioChannel::Music,
+            looped: true,
+            volume: 1.0,
+            speed: 1.0,
+            fade_in: None,
         }
     }
+
+    /// Play louder or quieter than the other sounds of its channel.
+    pub fn with_volume(mut self, volume: f32) -> Self {
+        self.volume = volume;
+        self
+    }
+
+    pub fn with_speed(mut self, speed: f32) -> Self {
+        self.speed = speed;
+        self
+    }
+
+    /// Play through once, even on the music channel.
+    pub fn once(mut self) -> Self {
+        self.looped = false;
+        self
+    }
+
+    pub fn fading_in(mut self, seconds: f32) -> Self {
+        self.fade_in = Some(seconds);
+        self
+    }
+}
+
+/// Sends the sounds that may need to be stopped or faded later,
+/// returning the id to do it with.
+#[derive(SystemParam)]
+pub struct Sounds<'w> {
+    ids: ResMut<'w, SoundIds>,
+    audio_events: EventWriter<'w, AudioEvent>,
+}
+
+impl Sounds<'_> {
+    pub fn play(&mut self, mut audio_event: AudioEvent) -> SoundId {
+        let id = self.ids.next_id();
+
+        audio_event.id = Some(id);
+        self.audio_events.send(audio_event);
+        id
+    }
+}
+
+/// Event triggers to change a sound, or end it before it finishes.
+#[derive(Event)]
+pub enum AudioControl {
+    Stop(SoundId),
+    /// Lower the volume down to silence over the given seconds, then stop.
+    FadeOut(SoundId, f32),
+    /// Play louder or quieter than the other sounds of its channel.
+    Volume(SoundId, f32),
 }
@@ -1,38 +1,459 @@
 automod::dir!(pub "src/game/audio");
 
-use bevy::{audio::Volume, prelude::*};
-use components::AmbientMusic;
-use events::AudioEvent;
+use super::{
+    button::{
+        components::{BackButton, SpriteButton},
+        spawn_labelled, Back, ButtonSystem,
+    },
+    controls::components::Controls,
+    menu::components::SettingsReturn,
+    score::components::Score,
+    AudioAssets, FontAssets,
+};
+use crate::{
+    despawn_recursive, ternary, BasicMath, GameState, Switcher, SCREEN_HEIGHT, SCREEN_WIDTH,
+};
+use bevy::{
+    audio::{AudioSinkPlayback, Volume},
+    prelude::*,
+};
+use components::*;
+use events::{AudioControl, AudioEvent, Sounds};
+use std::marker::PhantomData;
 
 /// Audio logic.
+///
+/// Every sound plays on a channel of the `Mixer`, tuned on the audio screen.
 pub struct AudioPlugin;
 
+impl AudioPlugin {
+    /// Copies of a sound effect playing at once, the oldest one stops for a new one.
+    pub const MAX_INSTANCES: usize = 3;
+}
+
 impl Plugin for AudioPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_plugins((SoundPlugin::<AudioSink>::default(), MusicPlugin))
+            .init_resource::<MixerCursor>()
+            .add_systems(
+                Update,
+                (
+                    toggle_mute.before(SoundSystem),
+                    open.run_if(in_state(GameState::Ready)),
+                    (browse.after(ButtonSystem), display_rows)
+                        .chain()
+                        .run_if(in_state(GameState::Audio)),
+                ),
+            )
+            .add_systems(OnEnter(GameState::Audio), spawn)
+            .add_systems(OnExit(GameState::Audio), despawn_recursive::<MixerScreen>);
+    }
+}
+
+/// Systems playing, controlling and despawning the sounds.
+#[derive(SystemSet, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SoundSystem;
+
+/// Sound logic.
+///
+/// Plays the sounds sent through `AudioEvent` and `AudioControl` with the `Mixer` volumes,
+/// through the sink `S` the audio output gives them once they start.
+/// Sounds are despawned once they have finished or been stopped, only by `clean_up`.
+pub struct SoundPlugin<S>(PhantomData<S>);
+
+impl<S> Default for SoundPlugin<S> {
+    fn default() -> Self {
+        SoundPlugin(PhantomData)
+    }
+}
+
+impl<S: Component + AudioSinkPlayback> Plugin for SoundPlugin<S> {
     fn build(&self, app: &mut App) {
         app.add_event::<AudioEvent>()
-            .add_systems(Update, play_audio);
+            .add_event::<AudioControl>()
+            .init_resource::<Mixer>()
+            .init_resource::<SoundIds>()
+            .add_systems(
+                Update,
+                (
+                    play_audio,
+                    control_audio,
+                    fade,
+                    apply_mixer::<S>,
+                    clean_up::<S>,
+                )
+                    .chain()
+                    .in_set(SoundSystem),
+            );
     }
 }
 
-fn play_audio(mut commands: Commands, mut audio_events: EventReader<AudioEvent>) {
+/// Music logic.
+///
+/// The music crossfades from one `Track` to another as the state changes,
+/// and a layer rises over the gameplay one as the score grows.
+pub struct MusicPlugin;
+
+impl Plugin for MusicPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<MusicDirector>().add_systems(
+            Update,
+            (
+                direct.run_if(state_changed::<GameState>.and_then(resource_exists::<AudioAssets>)),
+                intensify.run_if(resource_changed::<Score>),
+            )
+                .before(SoundSystem),
+        );
+    }
+}
+
+/// Fade the music of the last state out and the one of the new state in.
+fn direct(
+    state: Res<State<GameState>>,
+    score: Res<Score>,
+    audio_assets: Res<AudioAssets>,
+    mut director: ResMut<MusicDirector>,
+    mut sounds: Sounds,
+    mut audio_controls: EventWriter<AudioControl>,
+) {
+    let track = Track::for_state(*state.get());
+
+    if director.track() == track {
+        return;
+    }
+
+    let playing = director.playing.take().map(|(_, id)| id);
+
+    for id in playing.into_iter().chain(director.layer.take()) {
+        audio_controls.send(AudioControl::FadeOut(id, Track::CROSSFADE));
+    }
+
+    let Some(track) = track else {
+        return;
+    };
+
+    let mut music = AudioEvent::music(track.source(&audio_assets))
+        .with_volume(track.volume())
+        .fading_in(Track::CROSSFADE);
+
+    if !track.looped() {
+        music = music.once();
+    }
+
+    director.playing = Some((track, sounds.play(music)));
+
+    if track == Track::Gameplay {
+        let layer = AudioEvent::music(&audio_assets.layer)
+            .with_volume(Track::layer_volume(score.current))
+            .fading_in(Track::CROSSFADE);
+
+        director.layer = Some(sounds.play(layer));
+    }
+}
+
+/// Turn the gameplay layer up as the score grows.
+fn intensify(
+    score: Res<Score>,
+    director: Res<MusicDirector>,
+    mut audio_controls: EventWriter<AudioControl>,
+) {
+    if let Some(id) = director.layer {
+        audio_controls.send(AudioControl::Volume(id, Track::layer_volume(score.current)));
+    }
+}
+
+fn play_audio(
+    mut commands: Commands,
+    mut audio_events: EventReader<AudioEvent>,
+    mut ids: ResMut<SoundIds>,
+    sounds: Query<(Entity, &Sound, &Handle<AudioSource>, &AudioChannel), Without<Stopped>>,
+) {
     if audio_events.is_empty() {
         return;
     }
 
+    // Sound effects already playing, oldest first.
+    let mut effects: Vec<(SoundId, Entity, AssetId<AudioSource>)> = sounds
+        .iter()
+        .filter(|(.., channel)| **channel == AudioChannel::Sfx)
+        .map(|(entity, sound, source, _)| (sound.0, entity, source.id()))
+        .collect();
+    effects.sort_by_key(|(id, ..)| *id);
+
     for audio_event in audio_events.read() {
-        if audio_event.looped {
-            commands.spawn((
-                AudioBundle {
-                    source: audio_event.source.clone(),
-                    settings: PlaybackSettings::LOOP.with_volume(Volume::new(audio_event.volume)),
-                },
-                AmbientMusic,
-            ));
-        } else {
-            commands.spawn(AudioBundle {
+        let id = audio_event.id.unwrap_or_else(|| ids.next_id());
+        let source = audio_event.source.id();
+
+        if audio_event.channel == AudioChannel::Sfx {
+            let copies: Vec<usize> = (0..effects.len())
+                .filter(|i| effects[*i].2 == source)
+                .collect();
+
+            if copies.len() >= AudioPlugin::MAX_INSTANCES {
+                let (_, oldest, _) = effects.remove(copies[0]);
+
+                commands.entity(oldest).insert(Stopped);
+            }
+        }
+
+        let settings = ternary!(
+            audio_event.looped,
+            PlaybackSettings::LOOP,
+            PlaybackSettings::ONCE
+        );
+        let mut sound = commands.spawn((
+            AudioBundle {
                 source: audio_event.source.clone(),
-                settings: PlaybackSettings::ONCE.with_volume(Volume::new(audio_event.volume)),
-            });
+                settings: settings
+                    .with_volume(Volume::new(audio_event.volume))
+                    .with_speed(audio_event.speed),
+            },
+            audio_event.channel,
+            Sound(id),
+        ));
+
+        if let Some(seconds) = audio_event.fade_in {
+            sound.insert(Fade::rise(seconds));
+        }
+
+        match audio_event.channel {
+            AudioChannel::Music => {
+                sound.insert(AmbientMusic);
+            }
+            AudioChannel::Sfx => effects.push((id, sound.id(), source)),
+        }
+    }
+}
+
+fn control_audio(
+    mut commands: Commands,
+    mut audio_controls: EventReader<AudioControl>,
+    mut sounds: Query<(Entity, &Sound, &mut PlaybackSettings, Option<&Fade>), Without<Stopped>>,
+) {
+    for audio_control in audio_controls.read() {
+        let (AudioControl::Stop(id) | AudioControl::FadeOut(id, _) | AudioControl::Volume(id, _)) =
+            audio_control;
+        let Some((entity, _, mut settings, fade)) =
+            sounds.iter_mut().find(|(_, sound, ..)| sound.0 == *id)
+        else {
+            continue;
+        };
+
+        match audio_control {
+            AudioControl::Stop(_) => {
+                commands.entity(entity).insert(Stopped);
+            }
+            // A sound still fading in falls from where it got to.
+            AudioControl::FadeOut(_, seconds) => {
+                let from = fade.map_or(1f32, Fade::level);
+
+                commands.entity(entity).insert(Fade::fall(*seconds, from));
+            }
+            // Applied to the sink by `apply_mixer`.
+            AudioControl::Volume(_, volume) => settings.volume = Volume::new(*volume),
         }
     }
 }
+
+fn fade(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut fades: Query<(Entity, &mut Fade), Without<Stopped>>,
+) {
+    for (entity, mut fade) in &mut fades {
+        // Sounds that rose were set to their full volume on the last frame.
+        if fade.timer.finished() {
+            commands.entity(entity).remove::<Fade>();
+            continue;
+        }
+
+        if fade.timer.tick(time.delta()).finished() && fade.to == 0f32 {
+            commands.entity(entity).insert(Stopped);
+        }
+    }
+}
+
+fn toggle_mute(controls: Res<Controls>, mut mixer: ResMut<Mixer>) {
+    if controls.mute {
+        mixer.muted.toggle();
+    }
+}
+
+/// Sink of a sound, with what its volume is made of.
+type MixedSound<'a, S> = (
+    Ref<'a, S>,
+    Ref<'a, PlaybackSettings>,
+    &'a AudioChannel,
+    Option<&'a Fade>,
+);
+
+/// Set the volume of the sounds that just started playing, are fading or were turned up or down,
+/// or of all of them when the mixer changes.
+fn apply_mixer<S: Component + AudioSinkPlayback>(mixer: Res<Mixer>, sinks: Query<MixedSound<S>>) {
+    for (sink, settings, channel, fade) in &sinks {
+        if mixer.is_changed() || sink.is_added() || settings.is_changed() || fade.is_some() {
+            let level = fade.map_or(1f32, Fade::level);
+
+            sink.set_volume(settings.volume.get() * mixer.volume(*channel) * level);
+        }
+    }
+}
+
+/// Despawn the sounds that have finished or were stopped,
+/// the only system to despawn them so that none is despawned twice.
+fn clean_up<S: Component + AudioSinkPlayback>(
+    mut commands: Commands,
+    stopped: Query<Entity, With<Stopped>>,
+    sinks: Query<(Entity, &S), Without<Stopped>>,
+) {
+    for entity in &stopped {
+        commands.entity(entity).despawn();
+    }
+
+    for (entity, sink) in &sinks {
+        if sink.empty() {
+            commands.entity(entity).despawn();
+        }
+    }
+}
+
+fn open(
+    controls: Res<Controls>,
+    mut settings_return: ResMut<SettingsReturn>,
+    mut next_state: ResMut<NextState<GameState>>,
+) {
+    if controls.open_audio {
+        settings_return.0 = GameState::Ready;
+        next_state.set(GameState::Audio);
+    }
+}
+
+fn spawn(mut commands: Commands, font_assets: Res<FontAssets>, mut cursor: ResMut<MixerCursor>) {
+    *cursor = MixerCursor::default();
+
+    let text_style = TextStyle {
+        font: font_assets.teko_bold.clone(),
+        font_size: 24f32,
+        ..default()
+    };
+
+    let screen = commands
+        .spawn((
+            SpriteBundle {
+                transform: Transform::from_xyz(0f32, 0f32, 0.9),
+                sprite: Sprite {
+                    color: Color::rgba(0f32, 0f32, 0f32, 0.75),
+                    custom_size: Some(Vec2::new(SCREEN_WIDTH, SCREEN_HEIGHT)),
+                    ..default()
+                },
+                ..default()
+            },
+            MixerScreen,
+        ))
+        .with_children(|builder| {
+            builder.spawn(Text2dBundle {
+                text: Text::from_section(
+                    "AUDIO",
+                    TextStyle {
+                        font_size: 48f32,
+                        ..text_style.clone()
+                    },
+                ),
+                transform: Transform::from_xyz(0f32, 215f32, 0.1),
+                ..default()
+            });
+
+            let rows = Slider::ALL.map(Some).into_iter().chain([None]);
+
+            for (i, slider) in rows.enumerate() {
+                builder.spawn((
+                    Text2dBundle {
+                        text: Text::from_section("", text_style.clone())
+                            .with_justify(JustifyText::Center),
+                        transform: Transform::from_xyz(0f32, 130f32 - 56f32 * i as f32, 0.1),
+                        ..default()
+                    },
+                    MixerRow(slider),
+                ));
+            }
+
+            builder.spawn(Text2dBundle {
+                text: Text::from_section(
+                    "ARROWS: VOLUME   ENTER: MUTE   ESC: BACK",
+                    TextStyle {
+                        font_size: 18f32,
+                        ..text_style.clone()
+                    },
+                ),
+                transform: Transform::from_xyz(0f32, -SCREEN_HEIGHT.half() + 24f32, 0.1),
+                ..default()
+            });
+        })
+        .id();
+
+    spawn_labelled(
+        &mut commands,
+        SpriteButton {
+            color: Color::rgb(0.98, 0.6, 0.14),
+        },
+        Vec2::new(114f32, 28f32),
+        Transform::from_xyz(0f32, -110f32, 0.1),
+        Text::from_section("BACK", text_style),
+    )
+    .insert(BackButton)
+    .set_parent(screen);
+}
+
+fn browse(
+    controls: Res<Controls>,
+    mut back: Back,
+    settings_return: Res<SettingsReturn>,
+    mut cursor: ResMut<MixerCursor>,
+    mut mixer: ResMut<Mixer>,
+    mut next_state: ResMut<NextState<GameState>>,
+) {
+    if back.pressed() || controls.open_audio {
+        next_state.set(settings_return.0);
+    }
+
+    if controls.down {
+        cursor.next();
+    }
+
+    if controls.up {
+        cursor.prev();
+    }
+
+    let steps = ternary!(controls.right, 1f32, 0f32) - ternary!(controls.left, 1f32, 0f32);
+
+    match cursor.slider() {
+        Some(slider) if steps != 0f32 => mixer.nudge(slider, steps),
+        None if controls.confirm || steps != 0f32 => mixer.muted.toggle(),
+        _ => {}
+    }
+}
+
+fn display_rows(
+    cursor: Res<MixerCursor>,
+    mixer: Res<Mixer>,
+    mut rows: Query<(&MixerRow, &mut Text)>,
+) {
+    if !cursor.is_changed() && !mixer.is_changed() {
+        return;
+    }
+
+    for (MixerRow(slider), mut text) in &mut rows {
+        let highlighted = *slider == cursor.slider();
+        let section = &mut text.sections[0];
+
+        section.value = match slider {
+            Some(slider) => format!(
+                "{}\n< {:.0}% >",
+                slider.name(),
+                mixer.level(*slider) * 100f32
+            ),
+            None => format!("MUTED\n{}", ternary!(mixer.muted, "YES", "NO")),
+        };
+        section.style.color = ternary!(highlighted, Color::WHITE, Color::GRAY);
+    }
+}
@@ -0,0 +1,44 @@
+use bevy::prelude::*;
+
+/// Bot flying the bird on behalf of the player.
+#[derive(Resource, Default)]
+pub struct Autopilot {
+    /// Turned on by the player for every run.
+    pub enabled: bool,
+    /// The current run is a demo started from the idle ready screen.
+    pub demo: bool,
+    /// The bot flew some of the current run, even if it was turned off since.
+    pub took_over: bool,
+}
+
+impl Autopilot {
+    /// Height above the bottom of the next gap under which the bot flaps.
+    pub const MARGIN: f32 = 16f32;
+    /// Idle time on the ready screen before a demo starts, in seconds.
+    pub const ATTRACT_DELAY: f32 = 10f32;
+
+    pub fn is_flying(&self) -> bool {
+        self.enabled || self.demo
+    }
+
+    /// Runs flown by the bot, even for a moment, don't count toward the player progress.
+    pub fn has_flown(&self) -> bool {
+        self.is_flying() || self.took_over
+    }
+}
+
+/// Counts down the idle time on the ready screen.
+#[derive(Resource)]
+pub(super) struct AttractTimer(pub Timer);
+
+impl Default for AttractTimer {
+    fn default() -> Self {
+        AttractTimer(Timer::from_seconds(
+            Autopilot::ATTRACT_DELAY,
+            TimerMode::Once,
+        ))
+    }
+}
+
+#[derive(Component)]
+pub(super) struct AutopilotIndicator;
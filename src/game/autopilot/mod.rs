@@ -0,0 +1,179 @@
+automod::dir!(pub "src/game/autopilot");
+
+use super::{
+    bird::{components::Bird, events::FlapAction},
+    controls::components::Controls,
+    interpolation::components::Interpolated,
+    pipe::components::Pipe,
+    replay::components::Playback,
+    FontAssets, LaunchOptions, Simulation,
+};
+use crate::{ternary, BasicMath, GameState, SCREEN_HEIGHT, SCREEN_WIDTH};
+use bevy::{prelude::*, sprite::Anchor};
+use components::*;
+
+/// Autopilot logic.
+pub struct AutopilotPlugin;
+
+impl Plugin for AutopilotPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<Autopilot>()
+            .init_resource::<AttractTimer>()
+            .add_systems(Startup, engage)
+            .add_systems(OnExit(GameState::AssetLoading), spawn_indicator)
+            .add_systems(
+                FixedUpdate,
+                steer
+                    .in_set(Simulation::Input)
+                    .run_if(in_state(GameState::Playing).and_then(flying)),
+            )
+            .add_systems(
+                Update,
+                (
+                    toggle.run_if(in_state(GameState::Ready).or_else(in_state(GameState::Playing))),
+                    display_indicator,
+                    attract.run_if(
+                        in_state(GameState::Ready).and_then(not(resource_exists::<Playback>)),
+                    ),
+                    interrupt_demo.run_if(in_state(GameState::Playing).and_then(in_demo)),
+                    leave_demo.run_if(in_state(GameState::Over).and_then(in_demo)),
+                ),
+            )
+            .add_systems(OnEnter(GameState::Ready), (reset_attract_timer, hand_back))
+            .add_systems(OnExit(GameState::Over), end_demo);
+    }
+}
+
+/// Run condition of systems that only matter while the bot flies.
+pub fn flying(autopilot: Res<Autopilot>) -> bool {
+    autopilot.is_flying()
+}
+
+/// Run condition of systems keeping track of the player progress, which skip the run
+/// as soon as the bot flies any part of it.
+pub fn has_flown(autopilot: Res<Autopilot>) -> bool {
+    autopilot.has_flown()
+}
+
+fn in_demo(autopilot: Res<Autopilot>) -> bool {
+    autopilot.demo
+}
+
+fn engage(options: Res<LaunchOptions>, mut autopilot: ResMut<Autopilot>) {
+    autopilot.enabled = options.autopilot;
+}
+
+fn spawn_indicator(mut commands: Commands, font_assets: Res<FontAssets>) {
+    commands.spawn((
+        Text2dBundle {
+            text: Text::from_section(
+                "AUTOPILOT",
+                TextStyle {
+                    font: font_assets.teko_bold.clone(),
+                    font_size: 20f32,
+                    ..default()
+                },
+            ),
+            text_anchor: Anchor::TopLeft,
+            transform: Transform::from_xyz(
+                8f32 - SCREEN_WIDTH.half(),
+                SCREEN_HEIGHT.half() - 4f32,
+                0.9,
+            ),
+            visibility: Visibility::Hidden,
+            ..default()
+        },
+        AutopilotIndicator,
+    ));
+}
+
+fn display_indicator(
+    autopilot: Res<Autopilot>,
+    mut indicator: Query<&mut Visibility, With<AutopilotIndicator>>,
+) {
+    if !autopilot.is_changed() {
+        return;
+    }
+
+    for mut visibility in &mut indicator {
+        *visibility = ternary!(
+            autopilot.is_flying(),
+            Visibility::Visible,
+            Visibility::Hidden
+        );
+    }
+}
+
+fn toggle(controls: Res<Controls>, mut autopilot: ResMut<Autopilot>) {
+    if controls.autopilot {
+        autopilot.enabled = !autopilot.enabled;
+    }
+}
+
+/// Flap whenever the bird falls too close to the bottom of the next gap.
+fn steer(
+    bird: Query<(&Bird, &Interpolated)>,
+    pipes: Query<(&Pipe, &Interpolated), Without<Bird>>,
+    mut autopilot: ResMut<Autopilot>,
+    mut flap_action: EventWriter<FlapAction>,
+) {
+    if !autopilot.took_over {
+        autopilot.took_over = true;
+    }
+
+    let (bird, bird_position) = bird.single();
+    let bird_position = bird_position.current;
+
+    let next_gap_bottom = Pipe::next_gap(pipes.iter(), bird_position.x - Bird::WIDTH.half())
+        .map_or(0f32, |gap| gap.bottom);
+
+    let bird_bottom = bird_position.y - Bird::HEIGHT.half();
+
+    if bird.velocity > bird.flap.half() && bird_bottom < next_gap_bottom + Autopilot::MARGIN {
+        flap_action.send_default();
+    }
+}
+
+fn reset_attract_timer(mut timer: ResMut<AttractTimer>) {
+    timer.0.reset();
+}
+
+/// The next run belongs to the player until the bot flies again.
+fn hand_back(mut autopilot: ResMut<Autopilot>) {
+    autopilot.took_over = false;
+}
+
+/// Start a demo run once the ready screen has been left alone for a while.
+fn attract(
+    time: Res<Time>,
+    controls: Res<Controls>,
+    mut timer: ResMut<AttractTimer>,
+    mut autopilot: ResMut<Autopilot>,
+    mut next_state: ResMut<NextState<GameState>>,
+) {
+    if controls.any_held {
+        timer.0.reset();
+        return;
+    }
+
+    if timer.0.tick(time.delta()).just_finished() {
+        autopilot.demo = true;
+        next_state.set(GameState::Playing);
+    }
+}
+
+/// Any input stops the demo and brings the player back to the ready screen.
+fn interrupt_demo(controls: Res<Controls>, mut next_state: ResMut<NextState<GameState>>) {
+    if controls.any_pressed {
+        next_state.set(GameState::Over);
+    }
+}
+
+/// Demo runs skip the game over screen.
+fn leave_demo(mut next_state: ResMut<NextState<GameState>>) {
+    next_state.set(GameState::Ready);
+}
+
+fn end_demo(mut autopilot: ResMut<Autopilot>) {
+    autopilot.demo = false;
+}
@@ -1,4 +1,40 @@
+use crate::game::{interpolation::components::Interpolated, DateTime, ImageAssets};
 use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Serialize, Deserialize)]
+pub enum Scenery {
+    Day,
+    Night,
+    Dusk,
+}
+
+impl Scenery {
+    pub fn texture(self, image_assets: &ImageAssets) -> Handle<Image> {
+        match self {
+            Scenery::Day => image_assets.bg_day.clone(),
+            Scenery::Night => image_assets.bg_night.clone(),
+            Scenery::Dusk => image_assets.bg_dusk.clone(),
+        }
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Scenery::Day => "DAY",
+            Scenery::Night => "NIGHT",
+            Scenery::Dusk => "DUSK",
+        }
+    }
+}
+
+impl From<DateTime> for Scenery {
+    fn from(datetime: DateTime) -> Self {
+        match datetime {
+            DateTime::Day => Scenery::Day,
+            DateTime::Night => Scenery::Night,
+        }
+    }
+}
 
 #[derive(Component, Default, Clone, Copy)]
 pub struct Background {
@@ -15,13 +51,14 @@ impl Background {
     }
 
     #[inline]
-    pub fn generate_bundle(self, texture: &Handle<Image>) -> (SpriteBundle, Self) {
+    pub fn generate_bundle(self, texture: &Handle<Image>) -> (SpriteBundle, Interpolated, Self) {
         (
             SpriteBundle {
                 texture: texture.clone(),
                 transform: Transform::from_translation(self.translation),
                 ..default()
             },
+            Interpolated::new(self.translation),
             self,
         )
     }
@@ -1,7 +1,11 @@
-automod::dir!("src/game/background");
+automod::dir!(pub "src/game/background");
 
-use super::{DateTime, ImageAssets};
-use crate::{GameState, SCREEN_WIDTH};
+use super::{
+    config::components::GameConfig, difficulty::components::Difficulty,
+    interpolation::components::Interpolated, store::components::Loadout, DateTime, ImageAssets,
+    Simulation,
+};
+use crate::{ternary, GameState, SCREEN_WIDTH};
 use bevy::prelude::*;
 use components::Background;
 
@@ -17,18 +21,29 @@ impl Plugin for BackgroundPlugin {
                 replace_texture.run_if(has_existed),
             ),
         )
-        .add_systems(Update, moving.run_if(not(in_state(GameState::Over))));
+        .add_systems(
+            FixedUpdate,
+            moving
+                .in_set(Simulation::Movement)
+                .run_if(not(in_state(GameState::Over)))
+                .run_if(not(in_state(GameState::Paused))),
+        );
     }
 }
 
-fn spawn(mut commands: Commands, image_assets: Res<ImageAssets>, datetime: Res<DateTime>) {
+fn spawn(
+    mut commands: Commands,
+    image_assets: Res<ImageAssets>,
+    datetime: Res<DateTime>,
+    loadout: Res<Loadout>,
+) {
     let background = Background::new(0f32, 0f32, false);
-    let secondary_background =
-        Background::new(background.translation.x, background.translation.y, true);
-    let texture = match *datetime {
-        DateTime::Day => image_assets.bg_day.clone(),
-        DateTime::Night => image_assets.bg_night.clone(),
-    };
+    let secondary_background = Background::new(
+        background.translation.x + SCREEN_WIDTH,
+        background.translation.y,
+        true,
+    );
+    let texture = loadout.scenery(*datetime).texture(&image_assets);
 
     commands.spawn_batch(vec![
         background.generate_bundle(&texture),
@@ -39,12 +54,10 @@ fn spawn(mut commands: Commands, image_assets: Res<ImageAssets>, datetime: Res<D
 fn replace_texture(
     mut backgrounds: Query<&mut Handle<Image>, With<Background>>,
     datetime: Res<DateTime>,
+    loadout: Res<Loadout>,
     image_assets: Res<ImageAssets>,
 ) {
-    let new_texture = match *datetime {
-        DateTime::Day => image_assets.bg_day.clone(),
-        DateTime::Night => image_assets.bg_night.clone(),
-    };
+    let new_texture = loadout.scenery(*datetime).texture(&image_assets);
 
     for mut texture in &mut backgrounds {
         if *texture == new_texture {
@@ -59,14 +72,18 @@ fn has_existed(background: Query<(), With<Background>>) -> bool {
     !background.is_empty()
 }
 
-fn moving(mut background: Query<(&mut Background, &mut Transform)>) {
-    for (mut background, mut transform) in &mut background {
-        background.translation.x = (background.translation.x - 1.5f32) % SCREEN_WIDTH;
+fn moving(
+    time: Res<Time>,
+    difficulty: Res<Difficulty>,
+    config: Res<GameConfig>,
+    mut background: Query<(&Background, &mut Interpolated)>,
+) {
+    for (background, mut position) in &mut background {
+        position.current.x -= config.scroll.background * difficulty.speed * time.delta_seconds();
 
-        if background.secondary {
-            transform.translation.x = background.translation.x + SCREEN_WIDTH;
-        } else {
-            transform.translation.x = background.translation.x;
+        // Move back to the right once the background has scrolled its whole width.
+        if position.current.x <= ternary!(background.secondary, 0f32, -SCREEN_WIDTH) {
+            position.shift(Vec3::X * SCREEN_WIDTH);
         }
     }
 }
@@ -1,5 +1,5 @@
+use crate::{game::interpolation::components::Interpolated, BasicMath};
 use bevy::prelude::*;
-use flappybust::BasicMath;
 
 #[derive(Component, Default)]
 pub struct Base {
@@ -23,13 +23,14 @@ impl Base {
     }
 
     #[inline]
-    pub fn generate_bundle(self, texture: &Handle<Image>) -> (SpriteBundle, Self) {
+    pub fn generate_bundle(self, texture: &Handle<Image>) -> (SpriteBundle, Interpolated, Self) {
         (
             SpriteBundle {
                 texture: texture.clone(),
                 transform: Transform::from_translation(self.translation),
                 ..default()
             },
+            Interpolated::new(self.translation),
             self,
         )
     }
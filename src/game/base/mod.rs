@@ -1,17 +1,25 @@
 automod::dir!(pub "src/game/base");
 
-use super::ImageAssets;
-use crate::{GameState, SCREEN_HEIGHT};
+use super::{
+    config::components::GameConfig, difficulty::components::Difficulty,
+    interpolation::components::Interpolated, ImageAssets, Simulation,
+};
+use crate::{ternary, BasicMath, GameState, SCREEN_HEIGHT};
 use bevy::prelude::*;
 use components::Base;
-use flappybust::BasicMath;
 
 pub struct BasePlugin;
 
 impl Plugin for BasePlugin {
     fn build(&self, app: &mut App) {
         app.add_systems(OnEnter(GameState::Ready), spawn)
-            .add_systems(Update, moving.run_if(not(in_state(GameState::Over))));
+            .add_systems(
+                FixedUpdate,
+                moving
+                    .in_set(Simulation::Movement)
+                    .run_if(not(in_state(GameState::Over)))
+                    .run_if(not(in_state(GameState::Paused))),
+            );
     }
 }
 
@@ -30,14 +38,18 @@ fn spawn(mut commands: Commands, base: Query<(), With<Base>>, image_assets: Res<
     ]);
 }
 
-fn moving(mut base: Query<(&mut Base, &mut Transform)>) {
-    for (mut base, mut transform) in &mut base {
-        base.translation.x = (base.translation.x - 1.5f32) % Base::RESET_POINT;
-
-        if base.secondary {
-            transform.translation.x = base.translation.x + Base::RESET_POINT;
-        } else {
-            transform.translation.x = base.translation.x;
+fn moving(
+    time: Res<Time>,
+    difficulty: Res<Difficulty>,
+    config: Res<GameConfig>,
+    mut base: Query<(&Base, &mut Interpolated)>,
+) {
+    for (base, mut position) in &mut base {
+        position.current.x -= config.scroll.base * difficulty.speed * time.delta_seconds();
+
+        // Move back to the right once the base has scrolled past its reset point.
+        if position.current.x <= ternary!(base.secondary, 0f32, -Base::RESET_POINT) {
+            position.shift(Vec3::X * Base::RESET_POINT);
         }
     }
 }
@@ -1,13 +1,44 @@
-use bevy::prelude::*;
+use crate::{
+    game::{config::components::BirdConfig, difficulty::components::Difficulty, ImageAssets},
+    BasicMath,
+};
+use bevy::{math::bounding::Aabb2d, prelude::*};
 use rand::distributions::{Distribution, Standard};
+use serde::{Deserialize, Serialize};
 
-#[derive(Clone, Copy)]
-pub(super) enum BirdColor {
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Serialize, Deserialize)]
+pub enum BirdColor {
     Red,
     Blue,
     Yellow,
+    Purple,
+    Green,
 }
 
+impl BirdColor {
+    pub fn frames(self, image_assets: &ImageAssets) -> AnimationFrames {
+        match self {
+            BirdColor::Red => image_assets.red_birds.clone(),
+            BirdColor::Blue => image_assets.blue_birds.clone(),
+            BirdColor::Yellow => image_assets.yellow_birds.clone(),
+            BirdColor::Purple => image_assets.purple_birds.clone(),
+            BirdColor::Green => image_assets.green_birds.clone(),
+        }
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            BirdColor::Red => "RED",
+            BirdColor::Blue => "BLUE",
+            BirdColor::Yellow => "YELLOW",
+            BirdColor::Purple => "PURPLE",
+            BirdColor::Green => "GREEN",
+        }
+    }
+}
+
+/// Only the original colors are picked randomly,
+/// the others have to be bought from the store.
 impl Distribution<BirdColor> for Standard {
     fn sample<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> BirdColor {
         match rng.gen_range(0..3) {
@@ -42,30 +73,64 @@ pub(super) struct BouncingAnimation {
     pub velocity: f32,
 }
 
+impl BouncingAnimation {
+    /// Highest vertical speed reached while bouncing, in pixels per second.
+    pub const SPEED: f32 = 60f32;
+    /// How fast the bounce oscillates, in radians per second.
+    pub const FREQUENCY: f32 = 9.6;
+}
+
 #[derive(Component, Clone, Copy)]
 pub struct Bird {
     pub translation: Vec3,
     pub velocity: f32,
     pub gravity: f32,
+    /// Velocity given by a flap, in pixels per second (negative goes up).
+    pub flap: f32,
     pub rotation: f32,
+    pub color: BirdColor,
 }
 
 impl Bird {
     pub const WIDTH: f32 = 34f32;
     pub const HEIGHT: f32 = 24f32;
-    pub const DEFAULT_VELOCITY: f32 = -2.5;
-
-    pub fn new(x: f32, y: f32) -> Self {
+    pub fn new(
+        x: f32,
+        y: f32,
+        color: BirdColor,
+        difficulty: &Difficulty,
+        config: &BirdConfig,
+    ) -> Self {
         Bird {
             translation: Vec3::new(x, y, 0.3),
-            velocity: Self::DEFAULT_VELOCITY,
-            gravity: 0.098,
-            rotation: 25f32.to_radians(),
+            velocity: difficulty.flap,
+            gravity: difficulty.gravity,
+            flap: difficulty.flap,
+            rotation: config.highest_rotation.to_radians(),
+            color,
         }
     }
+
+    /// Follow another difficulty picked before the run starts.
+    pub fn retune(&mut self, difficulty: &Difficulty) {
+        self.velocity = difficulty.flap;
+        self.gravity = difficulty.gravity;
+        self.flap = difficulty.flap;
+    }
+
+    /// Bounding box of a bird located at `translation`.
+    pub fn bounding_box(translation: Vec3) -> Aabb2d {
+        Aabb2d::new(
+            translation.truncate(),
+            Vec2::new(Self::WIDTH, Self::HEIGHT).half(),
+        )
+    }
 }
 
 #[derive(Component)]
-pub(super) struct BirdSoul {
-    pub translation: Vec3,
+pub(super) struct BirdSoul;
+
+impl BirdSoul {
+    /// Pixels per second the soul rises at.
+    pub const SPEED: f32 = 60f32;
 }
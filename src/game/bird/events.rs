@@ -5,3 +5,12 @@ pub struct DeathEvent;
 
 #[derive(Default, Event)]
 pub struct InTheHeaven;
+
+/// Asks the bird to flap on the next simulation step,
+/// whoever is playing.
+#[derive(Default, Event)]
+pub struct FlapAction;
+
+/// Event triggers each time the bird flaps its wings.
+#[derive(Default, Event)]
+pub struct FlapEvent;
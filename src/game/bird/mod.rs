@@ -1,14 +1,16 @@
 automod::dir!(pub "src/game/bird");
 
 use super::{
-    audio::events::AudioEvent, base::components::Base, game_over::events::MedalDisplayed,
-    AudioAssets, GameState, ImageAssets,
+    audio::events::AudioEvent, base::components::Base, config::components::GameConfig,
+    controls::components::Controls, difficulty::components::Difficulty,
+    game_over::events::MedalDisplayed, interpolation::components::Interpolated,
+    replay::components::Playback, store::components::Loadout, AudioAssets, GameRng, GameState,
+    ImageAssets, Simulation,
 };
+use crate::{despawn, BasicMath, Switcher};
 use bevy::prelude::*;
 use components::*;
 use events::*;
-use flappybust::{despawn, BasicMath, Switcher};
-use rand::random;
 
 /// Bird logic.
 pub struct BirdPlugin;
@@ -16,36 +18,66 @@ pub struct BirdPlugin;
 impl Plugin for BirdPlugin {
     fn build(&self, app: &mut App) {
         app.add_event::<DeathEvent>()
-            .add_systems(OnEnter(GameState::Ready), spawn)
+            .add_event::<FlapAction>()
+            .add_event::<FlapEvent>()
             .add_systems(
-                Update,
+                OnEnter(GameState::Ready),
+                spawn.run_if(not(any_with_component::<Bird>)),
+            )
+            .add_systems(OnExit(GameState::Store), reskin)
+            .add_systems(
+                FixedUpdate,
                 (
                     bounce_vertical.run_if(in_state(GameState::Ready)),
                     (
-                        fall.run_if(not(in_state(GameState::Ready))),
-                        flap.run_if(not(in_state(GameState::Over))),
+                        fly.run_if(in_state(GameState::Playing)),
+                        fall.run_if(
+                            in_state(GameState::Playing).or_else(in_state(GameState::Over)),
+                        ),
                     )
-                        .run_if(not(in_state(GameState::AssetLoading))),
-                    fly.run_if(in_state(GameState::Playing)),
+                        .chain(),
+                )
+                    .in_set(Simulation::Movement),
+            )
+            .add_systems(
+                Update,
+                (
+                    flap.run_if(in_state(GameState::Ready).or_else(in_state(GameState::Playing))),
+                    read_input.run_if(
+                        in_state(GameState::Playing).and_then(not(resource_exists::<Playback>)),
+                    ),
                     bird_soul_fly.run_if(in_state(GameState::Over)),
+                    retune.run_if(
+                        in_state(GameState::Ready).and_then(resource_changed::<Difficulty>),
+                    ),
                 ),
             )
             .add_systems(OnEnter(GameState::Over), bird_soul_spawn)
             .add_systems(
                 OnExit(GameState::Over),
                 (despawn::<Bird>, despawn::<BirdSoul>),
+            )
+            .add_systems(
+                OnTransition {
+                    from: GameState::Paused,
+                    to: GameState::Ready,
+                },
+                despawn::<Bird>,
             );
     }
 }
 
-fn spawn(mut commands: Commands, image_assets: Res<ImageAssets>) {
-    let bird_color = random::<BirdColor>();
-    let animation_frames = match bird_color {
-        BirdColor::Red => image_assets.red_birds.clone(),
-        BirdColor::Blue => image_assets.blue_birds.clone(),
-        BirdColor::Yellow => image_assets.yellow_birds.clone(),
-    };
-    let bird = Bird::new(-53f32, 9f32);
+fn spawn(
+    mut commands: Commands,
+    image_assets: Res<ImageAssets>,
+    loadout: Res<Loadout>,
+    difficulty: Res<Difficulty>,
+    config: Res<GameConfig>,
+    mut rng: ResMut<GameRng>,
+) {
+    let color = loadout.bird_color(&mut rng);
+    let animation_frames = color.frames(&image_assets);
+    let bird = Bird::new(-53f32, 9f32, color, &difficulty, &config.bird);
 
     commands.spawn((
         bird,
@@ -54,11 +86,35 @@ fn spawn(mut commands: Commands, image_assets: Res<ImageAssets>) {
             transform: Transform::from_translation(bird.translation),
             ..default()
         },
+        Interpolated::new(bird.translation),
         FlapAnimation::new(0.12, animation_frames),
         BouncingAnimation::default(),
     ));
 }
 
+/// Apply the color equipped in the store to the waiting bird.
+fn reskin(
+    mut bird: Query<(&mut Bird, &mut FlapAnimation, &mut Handle<Image>)>,
+    image_assets: Res<ImageAssets>,
+    loadout: Res<Loadout>,
+) {
+    let Some(color) = loadout.bird else {
+        return;
+    };
+
+    for (mut bird, mut animation, mut texture) in &mut bird {
+        bird.color = color;
+        animation.frames = color.frames(&image_assets);
+        *texture = animation.frames[animation.current_frame].clone();
+    }
+}
+
+fn retune(mut bird: Query<&mut Bird>, difficulty: Res<Difficulty>) {
+    for mut bird in &mut bird {
+        bird.retune(&difficulty);
+    }
+}
+
 fn bird_soul_spawn(
     mut commands: Commands,
     bird: Query<&Bird>,
@@ -80,12 +136,13 @@ fn bird_soul_spawn(
             transform: Transform::from_translation(translation),
             ..default()
         },
-        BirdSoul { translation },
+        BirdSoul,
     ));
 }
 
 fn bird_soul_fly(
-    mut bird_soul: Query<(&mut Transform, &mut Visibility, &BirdSoul)>,
+    time: Res<Time>,
+    mut bird_soul: Query<(&mut Transform, &mut Visibility), With<BirdSoul>>,
     mut audio_event: EventWriter<AudioEvent>,
     audio_assets: Res<AudioAssets>,
     medal_event: EventReader<MedalDisplayed>,
@@ -95,28 +152,33 @@ fn bird_soul_fly(
         return;
     }
 
-    let (mut transform, mut visibility, bird_soul) = bird_soul.single_mut();
+    let (mut transform, mut visibility) = bird_soul.single_mut();
 
     if matches!(*visibility, Visibility::Hidden) {
         visibility.on();
+        audio_event.send(AudioEvent::sfx(&audio_assets.heaven));
     }
 
-    transform.translation.y += 1f32;
-
-    if transform.translation.y == bird_soul.translation.y + 1f32 {
-        audio_event.send(AudioEvent::new(&audio_assets.heaven, false));
-    }
+    transform.translation.y += BirdSoul::SPEED * time.delta_seconds();
 
     if transform.translation.y >= 267f32 {
         in_heaven.send_default();
     }
 }
 
-fn fall(mut bird: Query<(&mut Bird, &mut Transform)>, death_event: EventReader<DeathEvent>) {
-    let (mut bird, mut bird_transform) = bird.single_mut();
+fn fall(
+    time: Res<Time>,
+    config: Res<GameConfig>,
+    mut bird: Query<(&mut Bird, &mut Interpolated, &mut Transform)>,
+    death_event: EventReader<DeathEvent>,
+) {
+    let (mut bird, mut position, mut bird_transform) = bird.single_mut();
+    let delta = time.delta_seconds();
 
-    // Rotate down at min -90deg and rotate up at max 25deg.
-    bird.rotation = (bird.rotation - 40f32.recip()).clamp(-90f32.to_radians(), 25f32.to_radians());
+    bird.rotation = (bird.rotation - config.bird.rotation_speed.to_radians() * delta).clamp(
+        config.bird.lowest_rotation.to_radians(),
+        config.bird.highest_rotation.to_radians(),
+    );
 
     // Don't try to fall if bird has been already dead,
     // just lay on the ground.
@@ -124,31 +186,47 @@ fn fall(mut bird: Query<(&mut Bird, &mut Transform)>, death_event: EventReader<D
         return;
     }
 
-    bird.velocity += bird.gravity;
+    bird.velocity += bird.gravity * delta;
 
-    bird_transform.translation.y -= bird.velocity;
+    position.current.y -= bird.velocity * delta;
     bird_transform.rotation = Quat::from_rotation_z(bird.rotation);
 }
 
+fn read_input(
+    controls: Res<Controls>,
+    audio_assets: Res<AudioAssets>,
+    mut audio_event: EventWriter<AudioEvent>,
+    mut flap_action: EventWriter<FlapAction>,
+) {
+    if controls.flap {
+        flap_action.send_default();
+    }
+
+    if controls.flap_released {
+        audio_event.send(AudioEvent::sfx(&audio_assets.swoosh));
+    }
+}
+
 fn fly(
     mut bird: Query<&mut Bird>,
-    keys: Res<ButtonInput<KeyCode>>,
-    buttons: Res<ButtonInput<MouseButton>>,
+    config: Res<GameConfig>,
+    mut flap_action: EventReader<FlapAction>,
     audio_assets: Res<AudioAssets>,
     mut audio_event: EventWriter<AudioEvent>,
+    mut flap_event: EventWriter<FlapEvent>,
 ) {
-    let mut bird = bird.single_mut();
+    // Several requests on the same step still make a single flap.
+    if flap_action.read().count() == 0 {
+        return;
+    }
 
-    if keys.just_pressed(KeyCode::Space) || buttons.just_pressed(MouseButton::Left) {
-        audio_event.send(AudioEvent::new(&audio_assets.wing, false));
+    let mut bird = bird.single_mut();
 
-        bird.velocity = Bird::DEFAULT_VELOCITY;
-        bird.rotation = 25f32.to_radians();
-    }
+    audio_event.send(AudioEvent::sfx(&audio_assets.wing));
+    flap_event.send_default();
 
-    if keys.just_released(KeyCode::Space) || buttons.just_released(MouseButton::Left) {
-        audio_event.send(AudioEvent::new(&audio_assets.swoosh, false));
-    }
+    bird.velocity = bird.flap;
+    bird.rotation = config.bird.highest_rotation.to_radians();
 }
 
 fn flap(time: Res<Time>, mut bird: Query<(&mut FlapAnimation, &mut Handle<Image>), With<Bird>>) {
@@ -162,9 +240,13 @@ fn flap(time: Res<Time>, mut bird: Query<(&mut FlapAnimation, &mut Handle<Image>
     }
 }
 
-fn bounce_vertical(mut bird: Query<(&mut Transform, &mut BouncingAnimation), With<Bird>>) {
-    let (mut transform, mut bouncing_animation) = bird.single_mut();
+fn bounce_vertical(
+    time: Res<Time>,
+    mut bird: Query<(&mut Interpolated, &mut BouncingAnimation), With<Bird>>,
+) {
+    let (mut position, mut bouncing_animation) = bird.single_mut();
+    let delta = time.delta_seconds();
 
-    transform.translation.y += bouncing_animation.velocity.sin();
-    bouncing_animation.velocity += 0.16;
+    position.current.y += bouncing_animation.velocity.sin() * BouncingAnimation::SPEED * delta;
+    bouncing_animation.velocity += BouncingAnimation::FREQUENCY * delta;
 }
@@ -0,0 +1,46 @@
+use bevy::prelude::*;
+
+/// Sprite pressed with a click, a touch or the keyboard focus,
+/// hit-tested against its own size on screen.
+///
+/// How it is being pressed is kept in an `Interaction` next to it.
+#[derive(Component)]
+pub struct SpriteButton {
+    /// Color of the sprite when nothing is pointing at it.
+    pub color: Color,
+}
+
+impl Default for SpriteButton {
+    fn default() -> Self {
+        SpriteButton {
+            color: Color::WHITE,
+        }
+    }
+}
+
+impl SpriteButton {
+    /// Color of the sprite, darker when hovered and darker still when pressed.
+    pub fn tint(&self, interaction: Interaction) -> Color {
+        let factor = match interaction {
+            Interaction::None => 1f32,
+            Interaction::Hovered => 0.85,
+            Interaction::Pressed => 0.6,
+        };
+
+        Color::rgba(
+            self.color.r() * factor,
+            self.color.g() * factor,
+            self.color.b() * factor,
+            self.color.a(),
+        )
+    }
+}
+
+/// Button moved to with the arrow keys, pressed with enter.
+#[derive(Resource, Default)]
+pub struct ButtonFocus(pub Option<Entity>);
+
+/// Button leaving the screen it is on, or opening the menu from the ready screen,
+/// like the back binding.
+#[derive(Component)]
+pub struct BackButton;
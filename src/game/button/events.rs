@@ -0,0 +1,5 @@
+use bevy::prelude::*;
+
+/// Event triggers when a button is clicked, touched or confirmed.
+#[derive(Event)]
+pub struct ButtonClicked(pub Entity);
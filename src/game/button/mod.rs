@@ -0,0 +1,208 @@
+automod::dir!(pub "src/game/button");
+
+use super::{
+    controls::components::Controls, display::to_world, leaderboard::components::NameEntry,
+};
+use crate::{ternary, BasicMath, GameState};
+use bevy::{
+    ecs::{
+        query::QueryData,
+        system::{EntityCommands, SystemParam},
+    },
+    prelude::*,
+};
+use components::*;
+use events::*;
+
+/// Button logic.
+///
+/// Sprites with a `SpriteButton` light up under the cursor or the keyboard focus
+/// and send `ButtonClicked` when a click or touch is released over them.
+pub struct ButtonPlugin;
+
+/// Systems sending `ButtonClicked`, to run anything reading it after them.
+#[derive(SystemSet, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ButtonSystem;
+
+impl Plugin for ButtonPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<ButtonClicked>()
+            .init_resource::<ButtonFocus>()
+            .add_systems(
+                Update,
+                (
+                    // Enter finishes the name being typed rather than a button,
+                    // and picks a row on the screens with arrows of their own.
+                    (
+                        move_focus.run_if(
+                            in_state(GameState::Over).and_then(not(resource_exists::<NameEntry>)),
+                        ),
+                        point,
+                    )
+                        .chain()
+                        .in_set(ButtonSystem),
+                    display.after(ButtonSystem),
+                ),
+            );
+    }
+}
+
+/// Spawn a flat button of `size` with `label` written on it.
+pub fn spawn_labelled<'a>(
+    commands: &'a mut Commands,
+    button: SpriteButton,
+    size: Vec2,
+    transform: Transform,
+    label: Text,
+) -> EntityCommands<'a> {
+    let mut entity = commands.spawn((
+        SpriteBundle {
+            sprite: Sprite {
+                color: button.color,
+                custom_size: Some(size),
+                ..default()
+            },
+            transform,
+            ..default()
+        },
+        button,
+        Interaction::default(),
+    ));
+
+    entity.with_children(|builder| {
+        builder.spawn(Text2dBundle {
+            text: label,
+            transform: Transform::from_xyz(0f32, 0f32, 0.1),
+            ..default()
+        });
+    });
+
+    entity
+}
+
+/// The back binding, or a click on a `BackButton`.
+/// Read it after `ButtonSystem`.
+#[derive(SystemParam)]
+pub struct Back<'w, 's> {
+    controls: Res<'w, Controls>,
+    clicked: EventReader<'w, 's, ButtonClicked>,
+    buttons: Query<'w, 's, &'static Interaction, With<BackButton>>,
+}
+
+impl Back<'_, '_> {
+    /// A click or touch went down on a `BackButton` and has not gone up yet.
+    pub fn held(&self) -> bool {
+        self.buttons
+            .iter()
+            .any(|interaction| *interaction == Interaction::Pressed)
+    }
+
+    pub fn pressed(&mut self) -> bool {
+        let clicked = self
+            .clicked
+            .read()
+            .any(|ButtonClicked(button)| self.buttons.contains(*button));
+
+        self.controls.back || clicked
+    }
+}
+
+/// Move the focus between the shown buttons from left to right,
+/// and click the focused one on enter.
+fn move_focus(
+    controls: Res<Controls>,
+    buttons: Query<(Entity, &GlobalTransform, &Visibility), With<SpriteButton>>,
+    mut focus: ResMut<ButtonFocus>,
+    mut clicked: EventWriter<ButtonClicked>,
+) {
+    let mut shown: Vec<(Entity, f32)> = buttons
+        .iter()
+        .filter(|(_, _, visibility)| **visibility != Visibility::Hidden)
+        .map(|(entity, transform, _)| (entity, transform.translation().x))
+        .collect();
+    shown.sort_by(|(_, a), (_, b)| a.total_cmp(b));
+
+    let focused = shown
+        .iter()
+        .position(|(entity, _)| focus.0 == Some(*entity));
+
+    if controls.left || controls.right {
+        let index = match focused {
+            Some(index) if controls.right => (index + 1).min(shown.len() - 1),
+            Some(index) => index.saturating_sub(1),
+            None => ternary!(controls.right, 0, shown.len().saturating_sub(1)),
+        };
+
+        focus.0 = shown.get(index).map(|(entity, _)| *entity);
+    } else if let (true, Some(index)) = (controls.confirm, focused) {
+        clicked.send(ButtonClicked(shown[index].0));
+    }
+}
+
+/// What a button is hit-tested against.
+#[derive(QueryData)]
+#[query_data(mutable)]
+struct PointedButton {
+    entity: Entity,
+    interaction: &'static mut Interaction,
+    visibility: &'static Visibility,
+    sprite: &'static Sprite,
+    texture: &'static Handle<Image>,
+    transform: &'static GlobalTransform,
+}
+
+/// Press the buttons under a click or touch going down,
+/// and click them if it goes up while still over them.
+fn point(
+    controls: Res<Controls>,
+    camera: Query<(&Camera, &GlobalTransform)>,
+    images: Option<Res<Assets<Image>>>,
+    focus: Res<ButtonFocus>,
+    mut buttons: Query<PointedButton, With<SpriteButton>>,
+    mut clicked: EventWriter<ButtonClicked>,
+) {
+    let camera = camera.get_single().ok();
+    let cursor = controls.cursor.and_then(|cursor| to_world(cursor, camera));
+    let pointer = controls
+        .pointer
+        .and_then(|pointer| to_world(pointer, camera));
+
+    for mut button in &mut buttons {
+        if *button.visibility == Visibility::Hidden {
+            button.interaction.set_if_neq(Interaction::None);
+            continue;
+        }
+
+        // The size of the image is unknown until it is loaded.
+        let Some(size) = button
+            .sprite
+            .custom_size
+            .or_else(|| images.as_ref()?.get(button.texture).map(Image::size_f32))
+        else {
+            continue;
+        };
+
+        let (scale, _, center) = button.transform.to_scale_rotation_translation();
+        let half_size = (size * scale.truncate()).abs().half();
+        let over = |position: Vec2| (position - center.truncate()).abs().cmple(half_size).all();
+
+        let hovered = cursor.is_some_and(over);
+        let pressed = *button.interaction == Interaction::Pressed || pointer.is_some_and(over);
+
+        if pressed && controls.pointer_released && hovered {
+            clicked.send(ButtonClicked(button.entity));
+        }
+
+        button.interaction.set_if_neq(match () {
+            _ if pressed && !controls.pointer_released => Interaction::Pressed,
+            _ if hovered || focus.0 == Some(button.entity) => Interaction::Hovered,
+            _ => Interaction::None,
+        });
+    }
+}
+
+fn display(mut buttons: Query<(&SpriteButton, &Interaction, &mut Sprite), Changed<Interaction>>) {
+    for (button, interaction, mut sprite) in &mut buttons {
+        sprite.color = button.tint(*interaction);
+    }
+}
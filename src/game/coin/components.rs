@@ -0,0 +1,41 @@
+use crate::game::interpolation::components::Interpolated;
+use bevy::prelude::*;
+
+#[derive(Component)]
+pub struct Coin;
+
+impl Coin {
+    pub const SIZE: f32 = 16f32;
+    /// Chance for a coin to appear between a pair of pipes.
+    pub const SPAWN_CHANCE: f64 = 0.5;
+
+    #[inline]
+    pub fn generate_bundle(
+        x: f32,
+        y: f32,
+        texture: &Handle<Image>,
+    ) -> (SpriteBundle, Interpolated, Self) {
+        let translation = Vec3::new(x, y, 0.15);
+
+        (
+            SpriteBundle {
+                texture: texture.clone(),
+                transform: Transform::from_translation(translation),
+                ..default()
+            },
+            Interpolated::new(translation),
+            Coin,
+        )
+    }
+}
+
+/// Coins owned by the player, kept across runs.
+#[derive(Resource, Default, Clone, Copy)]
+pub struct Wallet {
+    pub balance: usize,
+    /// Coins collected during the current run.
+    pub collected: usize,
+}
+
+#[derive(Component)]
+pub(super) struct CoinCounter;
@@ -0,0 +1,160 @@
+automod::dir!(pub "src/game/coin");
+
+use super::{
+    audio::events::AudioEvent, autopilot::components::Autopilot, bird::components::Bird,
+    config::components::GameConfig, difficulty::components::Difficulty,
+    interpolation::components::Interpolated, replay::components::Playback, AudioAssets, FontAssets,
+    ImageAssets, Simulation,
+};
+use crate::{despawn, despawn_recursive, BasicMath, GameState, SCREEN_HEIGHT, SCREEN_WIDTH};
+use bevy::{
+    ecs::system::SystemParam,
+    math::bounding::{Aabb2d, IntersectsVolume},
+    prelude::*,
+    sprite::Anchor,
+};
+use components::*;
+
+/// Coin logic.
+pub struct CoinPlugin;
+
+impl Plugin for CoinPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<Wallet>()
+            .add_systems(
+                OnTransition {
+                    from: GameState::Ready,
+                    to: GameState::Playing,
+                },
+                spawn_counter,
+            )
+            .add_systems(
+                FixedUpdate,
+                (
+                    moving.in_set(Simulation::Movement),
+                    collect.in_set(Simulation::Scoring),
+                )
+                    .run_if(in_state(GameState::Playing)),
+            )
+            .add_systems(Update, display_counter.run_if(in_state(GameState::Playing)))
+            .add_systems(OnEnter(GameState::Over), despawn_recursive::<CoinCounter>)
+            .add_systems(OnExit(GameState::Over), despawn::<Coin>)
+            .add_systems(
+                OnTransition {
+                    from: GameState::Paused,
+                    to: GameState::Ready,
+                },
+                (despawn::<Coin>, despawn_recursive::<CoinCounter>),
+            );
+    }
+}
+
+fn spawn_counter(
+    mut commands: Commands,
+    font_assets: Res<FontAssets>,
+    image_assets: Res<ImageAssets>,
+    mut wallet: ResMut<Wallet>,
+) {
+    wallet.collected = 0;
+
+    commands
+        .spawn((
+            Text2dBundle {
+                text: Text::from_section(
+                    wallet.collected.to_string(),
+                    TextStyle {
+                        font: font_assets.teko_bold.clone(),
+                        font_size: 32f32,
+                        ..default()
+                    },
+                ),
+                text_anchor: Anchor::CenterLeft,
+                transform: Transform::from_xyz(
+                    16f32 + Coin::SIZE - SCREEN_WIDTH.half(),
+                    SCREEN_HEIGHT.half() - 20f32,
+                    0.2,
+                ),
+                ..default()
+            },
+            CoinCounter,
+        ))
+        .with_children(|builder| {
+            builder.spawn(SpriteBundle {
+                texture: image_assets.coin.clone(),
+                transform: Transform::from_xyz(-Coin::SIZE.half() - 4f32, -2f32, 0f32),
+                ..default()
+            });
+        });
+}
+
+fn moving(
+    time: Res<Time>,
+    difficulty: Res<Difficulty>,
+    config: Res<GameConfig>,
+    mut commands: Commands,
+    mut coins: Query<(Entity, &mut Interpolated), With<Coin>>,
+) {
+    for (entity, mut position) in &mut coins {
+        position.current.x -= config.scroll.pipes * difficulty.speed * time.delta_seconds();
+
+        // Remove coins that are outside of screen.
+        if position.current.x <= -Coin::SIZE.half() - SCREEN_WIDTH.half() {
+            commands.entity(entity).despawn();
+        }
+    }
+}
+
+/// Whether the coins picked up go into the wallet,
+/// which they don't in a run the bot flew or a replay.
+#[derive(SystemParam)]
+struct Payout<'w> {
+    autopilot: Res<'w, Autopilot>,
+    playback: Option<Res<'w, Playback>>,
+}
+
+impl Payout<'_> {
+    fn pays(&self) -> bool {
+        !self.autopilot.has_flown() && self.playback.is_none()
+    }
+}
+
+fn collect(
+    mut commands: Commands,
+    bird: Query<&Interpolated, With<Bird>>,
+    coins: Query<(Entity, &Interpolated), With<Coin>>,
+    audio_assets: Res<AudioAssets>,
+    mut audio_event: EventWriter<AudioEvent>,
+    mut wallet: ResMut<Wallet>,
+    payout: Payout,
+) {
+    let bird_bounding_box = Bird::bounding_box(bird.single().current);
+
+    for (entity, position) in &coins {
+        let coin_bounding_box =
+            Aabb2d::new(position.current.truncate(), Vec2::splat(Coin::SIZE).half());
+
+        if bird_bounding_box.intersects(&coin_bounding_box) {
+            if payout.pays() {
+                wallet.balance += 1;
+            }
+
+            wallet.collected += 1;
+
+            audio_event.send(AudioEvent::sfx(&audio_assets.ding).with_volume(0.5));
+
+            commands.entity(entity).despawn();
+        }
+    }
+}
+
+fn display_counter(wallet: Res<Wallet>, mut counter: Query<&mut Text, With<CoinCounter>>) {
+    if !wallet.is_changed() {
+        return;
+    }
+
+    for mut text in &mut counter {
+        for section in &mut text.sections {
+            section.value = wallet.collected.to_string();
+        }
+    }
+}
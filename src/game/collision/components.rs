@@ -0,0 +1,15 @@
+/// What the bird can crash into.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Obstacle {
+    Ground,
+    Pipe,
+}
+
+impl Obstacle {
+    pub fn name(self) -> &'static str {
+        match self {
+            Obstacle::Ground => "ground",
+            Obstacle::Pipe => "pipe",
+        }
+    }
+}
@@ -1,4 +1,5 @@
+use super::components::Obstacle;
 use bevy::prelude::*;
 
-#[derive(Event, Default)]
-pub struct CollisionEvent;
+#[derive(Event)]
+pub struct CollisionEvent(pub Obstacle);
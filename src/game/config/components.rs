@@ -0,0 +1,103 @@
+use crate::game::difficulty::components::{DifficultyCurve, Preset};
+use bevy::{prelude::*, utils::HashMap};
+use serde::Deserialize;
+
+/// Tuning of the game, read from `assets/game.config.ron`.
+#[derive(Asset, Resource, TypePath, Deserialize, Clone, Debug)]
+pub struct GameConfig {
+    pub bird: BirdConfig,
+    pub scroll: ScrollConfig,
+    pub medals: MedalConfig,
+    pub game_over: GameOverConfig,
+    pub difficulty: HashMap<Preset, DifficultyCurve>,
+}
+
+impl Default for GameConfig {
+    fn default() -> Self {
+        let config: GameConfig = ron::from_str(include_str!("../../../assets/game.config.ron"))
+            .expect("Game config must be valid.");
+
+        config.validate().expect("Game config must be valid.");
+        config
+    }
+}
+
+impl GameConfig {
+    /// Check the ranges the game relies on, which an edited config could get backwards.
+    pub fn validate(&self) -> Result<(), String> {
+        if self.bird.lowest_rotation > self.bird.highest_rotation {
+            return Err("`bird.lowest_rotation` is above `bird.highest_rotation`".to_string());
+        }
+
+        if self.game_over.scoreboard_start > self.game_over.scoreboard_end {
+            return Err(
+                "`game_over.scoreboard_start` is above `game_over.scoreboard_end`".to_string(),
+            );
+        }
+
+        for (preset, curve) in &self.difficulty {
+            curve
+                .validate()
+                .map_err(|err| format!("difficulty {}: {err}", preset.name()))?;
+        }
+
+        Ok(())
+    }
+
+    /// Fingerprint of the tuning, for the score server
+    /// to only rank runs played with the config it plays them again with.
+    pub fn digest(&self) -> u64 {
+        let difficulty: Vec<_> = Preset::ALL
+            .iter()
+            .map(|preset| self.difficulty.get(preset))
+            .collect();
+        let text = format!(
+            "{:?} {:?} {:?} {:?} {difficulty:?}",
+            self.bird, self.scroll, self.medals, self.game_over
+        );
+
+        // FNV-1a, which unlike `DefaultHasher` is the same on every platform and release.
+        text.bytes().fold(0xcbf2_9ce4_8422_2325, |hash, byte| {
+            (hash ^ byte as u64).wrapping_mul(0x0100_0000_01b3)
+        })
+    }
+}
+
+#[derive(Deserialize, Clone, Copy, Debug)]
+pub struct BirdConfig {
+    /// How fast the bird rotates down, in degrees per second.
+    pub rotation_speed: f32,
+    /// Rotation after a flap, in degrees.
+    pub highest_rotation: f32,
+    /// Rotation once fully diving, in degrees.
+    pub lowest_rotation: f32,
+}
+
+/// Scrolling speeds, in pixels per second.
+#[derive(Deserialize, Clone, Copy, Debug)]
+pub struct ScrollConfig {
+    pub pipes: f32,
+    pub base: f32,
+    pub background: f32,
+}
+
+/// Lowest score earning each medal.
+#[derive(Deserialize, Clone, Copy, Debug)]
+pub struct MedalConfig {
+    pub bronze: usize,
+    pub silver: usize,
+    pub gold: usize,
+    pub platinum: usize,
+}
+
+#[derive(Deserialize, Clone, Copy, Debug)]
+pub struct GameOverConfig {
+    pub text_start: f32,
+    pub text_end: f32,
+    pub text_gravity: f32,
+    /// Part of its velocity the text keeps when bouncing.
+    pub text_bounce: f32,
+    pub scoreboard_start: f32,
+    pub scoreboard_end: f32,
+    pub scoreboard_gravity: f32,
+}
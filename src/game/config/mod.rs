@@ -0,0 +1,81 @@
+automod::dir!(pub "src/game/config");
+
+use bevy::{
+    asset::{io::Reader, AssetLoader, AsyncReadExt, LoadContext},
+    prelude::*,
+    utils::BoxedFuture,
+};
+use components::GameConfig;
+
+/// Config logic.
+///
+/// The gameplay starts with the config built into the game,
+/// then follows `assets/game.config.ron` each time it is loaded.
+/// A config that fails to load or validate is logged and the last one is kept.
+pub struct ConfigPlugin;
+
+impl Plugin for ConfigPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_asset::<GameConfig>()
+            .init_asset_loader::<GameConfigLoader>()
+            .add_systems(Startup, load)
+            .add_systems(Update, apply);
+    }
+}
+
+#[derive(Resource)]
+struct ConfigHandle(Handle<GameConfig>);
+
+#[derive(Default)]
+struct GameConfigLoader;
+
+impl AssetLoader for GameConfigLoader {
+    type Asset = GameConfig;
+    type Settings = ();
+    type Error = String;
+
+    fn load<'a>(
+        &'a self,
+        reader: &'a mut Reader,
+        _settings: &'a (),
+        _load_context: &'a mut LoadContext,
+    ) -> BoxedFuture<'a, Result<GameConfig, String>> {
+        Box::pin(async move {
+            let mut bytes = Vec::new();
+
+            reader
+                .read_to_end(&mut bytes)
+                .await
+                .map_err(|err| err.to_string())?;
+
+            let config: GameConfig = ron::de::from_bytes(&bytes).map_err(|err| err.to_string())?;
+
+            config.validate()?;
+            Ok(config)
+        })
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["config.ron"]
+    }
+}
+
+fn load(mut commands: Commands, asset_server: Res<AssetServer>) {
+    commands.insert_resource(ConfigHandle(asset_server.load("game.config.ron")));
+}
+
+fn apply(
+    mut asset_event: EventReader<AssetEvent<GameConfig>>,
+    handle: Res<ConfigHandle>,
+    configs: Res<Assets<GameConfig>>,
+    mut config: ResMut<GameConfig>,
+) {
+    for event in asset_event.read() {
+        if event.is_loaded_with_dependencies(&handle.0) || event.is_modified(&handle.0) {
+            if let Some(loaded) = configs.get(&handle.0) {
+                info!("Applying game config");
+                *config = loaded.clone();
+            }
+        }
+    }
+}
@@ -0,0 +1,251 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// What the player asked for on the current frame,
+/// whether it came from a keyboard, a mouse, a gamepad or a touch screen.
+#[derive(Resource, Default, Clone, Copy, Debug)]
+pub struct Controls {
+    /// A flap binding or a touch went down.
+    pub flap: bool,
+    /// A flap binding or a touch went up.
+    pub flap_released: bool,
+    /// Window position of the left click or touch that just went down, if any.
+    pub pointer: Option<Vec2>,
+    /// The left click or touch went up.
+    pub pointer_released: bool,
+    /// Window position of the mouse, or of the touch held down or just lifted.
+    pub cursor: Option<Vec2>,
+    pub pause: bool,
+    pub restart: bool,
+    pub mute: bool,
+    pub quit: bool,
+    /// Leave the current screen, or open the menu from the ready screen.
+    pub back: bool,
+    pub open_controls: bool,
+    pub open_audio: bool,
+    pub open_store: bool,
+    pub open_gallery: bool,
+    pub open_leaderboard: bool,
+    /// Switch to the next difficulty preset.
+    pub difficulty: bool,
+    pub autopilot: bool,
+    pub fullscreen: bool,
+    /// Enter, space or the gamepad south button went down.
+    pub confirm: bool,
+    /// Arrow keys or gamepad d-pad, to move through menus.
+    pub up: bool,
+    pub down: bool,
+    pub left: bool,
+    pub right: bool,
+    /// Any key, button or touch went down.
+    pub any_pressed: bool,
+    /// Any key, button or touch is held down.
+    pub any_held: bool,
+}
+
+/// Something the player can ask for, whatever it is bound to.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Serialize, Deserialize)]
+pub enum Action {
+    Flap,
+    Pause,
+    Restart,
+    Mute,
+    Quit,
+    Back,
+    /// Open the controls screen from the ready screen, or leave it.
+    Controls,
+    /// Open the audio screen from the ready screen, or leave it.
+    Audio,
+    /// Open the store from the ready screen, or leave it.
+    Store,
+    /// Open the achievement gallery from the ready screen, or leave it.
+    Gallery,
+    /// Open the leaderboard from the ready screen, or leave it.
+    Leaderboard,
+    /// Switch to the next difficulty on the ready screen.
+    Difficulty,
+    /// Hand the bird over to the bot, or take it back.
+    Autopilot,
+    Fullscreen,
+}
+
+impl Action {
+    pub const ALL: [Action; 14] = [
+        Self::Flap,
+        Self::Pause,
+        Self::Restart,
+        Self::Mute,
+        Self::Quit,
+        Self::Back,
+        Self::Controls,
+        Self::Audio,
+        Self::Store,
+        Self::Gallery,
+        Self::Leaderboard,
+        Self::Difficulty,
+        Self::Autopilot,
+        Self::Fullscreen,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Action::Flap => "FLAP",
+            Action::Pause => "PAUSE",
+            Action::Restart => "RESTART",
+            Action::Mute => "MUTE",
+            Action::Quit => "QUIT",
+            Action::Back => "BACK",
+            Action::Controls => "CONTROLS",
+            Action::Audio => "AUDIO",
+            Action::Store => "STORE",
+            Action::Gallery => "GALLERY",
+            Action::Leaderboard => "LEADERBOARD",
+            Action::Difficulty => "DIFFICULTY",
+            Action::Autopilot => "AUTOPILOT",
+            Action::Fullscreen => "FULLSCREEN",
+        }
+    }
+}
+
+/// Key or button an action can be bound to.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Serialize, Deserialize)]
+pub enum Binding {
+    Key(KeyCode),
+    Mouse(MouseButton),
+    Gamepad(GamepadButtonType),
+}
+
+impl Binding {
+    pub fn name(self) -> String {
+        let name = match self {
+            Binding::Key(code) => {
+                let name = format!("{code:?}");
+
+                // `KeyA` and `Digit1` read better as `A` and `1`.
+                match name.strip_prefix("Key").or(name.strip_prefix("Digit")) {
+                    Some(short) if short.len() == 1 => short.to_string(),
+                    _ => name,
+                }
+            }
+            Binding::Mouse(MouseButton::Other(button)) => format!("Mouse {button}"),
+            Binding::Mouse(button) => format!("{button:?} click"),
+            Binding::Gamepad(button) => format!("Pad {button:?}"),
+        };
+
+        name.to_uppercase()
+    }
+}
+
+/// Keys and buttons bound to each action, kept in the save file.
+#[derive(Resource, Clone, PartialEq, Debug, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Bindings(pub BTreeMap<Action, Vec<Binding>>);
+
+impl Default for Bindings {
+    fn default() -> Self {
+        use Binding::*;
+
+        Bindings(BTreeMap::from([
+            (
+                Action::Flap,
+                vec![
+                    Key(KeyCode::Space),
+                    Mouse(MouseButton::Left),
+                    Gamepad(GamepadButtonType::South),
+                ],
+            ),
+            (
+                Action::Pause,
+                vec![
+                    Key(KeyCode::KeyP),
+                    Key(KeyCode::Escape),
+                    Gamepad(GamepadButtonType::Start),
+                ],
+            ),
+            (
+                Action::Restart,
+                vec![
+                    Key(KeyCode::Space),
+                    Key(KeyCode::Enter),
+                    Gamepad(GamepadButtonType::South),
+                ],
+            ),
+            (
+                Action::Mute,
+                vec![Key(KeyCode::KeyM), Gamepad(GamepadButtonType::Select)],
+            ),
+            (Action::Quit, vec![Key(KeyCode::KeyQ)]),
+            (
+                Action::Back,
+                vec![Key(KeyCode::Escape), Gamepad(GamepadButtonType::East)],
+            ),
+            (Action::Controls, vec![Key(KeyCode::KeyC)]),
+            (Action::Audio, vec![Key(KeyCode::KeyV)]),
+            (Action::Store, vec![Key(KeyCode::KeyS)]),
+            (Action::Gallery, vec![Key(KeyCode::KeyG)]),
+            (Action::Leaderboard, vec![Key(KeyCode::KeyL)]),
+            (Action::Difficulty, vec![Key(KeyCode::KeyD)]),
+            (Action::Autopilot, vec![Key(KeyCode::KeyA)]),
+            (Action::Fullscreen, vec![Key(KeyCode::F11)]),
+        ]))
+    }
+}
+
+impl Bindings {
+    pub fn get(&self, action: Action) -> &[Binding] {
+        self.0.get(&action).map_or(&[], Vec::as_slice)
+    }
+
+    /// Give the actions added since these bindings were saved their default ones.
+    pub fn complete(mut self) -> Self {
+        for (action, bindings) in Bindings::default().0 {
+            self.0.entry(action).or_insert(bindings);
+        }
+
+        self
+    }
+
+    /// Bind `binding` to `action`, or unbind it if it already was.
+    pub fn toggle(&mut self, action: Action, binding: Binding) {
+        let bindings = self.0.entry(action).or_default();
+
+        match bindings.iter().position(|other| *other == binding) {
+            Some(index) => {
+                bindings.remove(index);
+            }
+            None => bindings.push(binding),
+        }
+    }
+}
+
+/// Row highlighted on the controls screen, the last one resets every binding.
+#[derive(Resource, Default)]
+pub(super) struct ControlsCursor {
+    pub row: usize,
+    /// Whether the next key or button goes to the highlighted action.
+    pub rebinding: bool,
+}
+
+impl ControlsCursor {
+    pub const ROWS: usize = Action::ALL.len() + 1;
+
+    pub fn action(&self) -> Option<Action> {
+        Action::ALL.get(self.row).copied()
+    }
+
+    pub fn next(&mut self) {
+        self.row = (self.row + 1) % Self::ROWS;
+    }
+
+    pub fn prev(&mut self) {
+        self.row = (self.row + Self::ROWS - 1) % Self::ROWS;
+    }
+}
+
+#[derive(Component)]
+pub(super) struct ControlsScreen;
+
+/// Text of a controls screen row, `None` being the reset row.
+#[derive(Component)]
+pub(super) struct ControlsRow(pub Option<Action>);
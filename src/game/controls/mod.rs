@@ -0,0 +1,379 @@
+automod::dir!(pub "src/game/controls");
+
+use super::{
+    button::{
+        components::{BackButton, SpriteButton},
+        spawn_labelled, Back, ButtonSystem,
+    },
+    leaderboard::components::NameEntry,
+    menu::components::SettingsReturn,
+    FontAssets,
+};
+use crate::{despawn_recursive, ternary, BasicMath, GameState, SCREEN_HEIGHT, SCREEN_WIDTH};
+use bevy::{
+    app::AppExit, ecs::system::SystemParam, input::InputSystem, prelude::*, window::PrimaryWindow,
+};
+use components::*;
+
+/// Controls logic.
+///
+/// Gathers every input device into `Controls` before any other system reads it,
+/// going through the `Bindings` the player chose on the controls screen.
+pub struct ControlsPlugin;
+
+impl Plugin for ControlsPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<Controls>()
+            .init_resource::<Bindings>()
+            .init_resource::<ControlsCursor>()
+            .add_systems(PreUpdate, read.after(InputSystem))
+            .add_systems(
+                Update,
+                (
+                    quit.run_if(
+                        in_state(GameState::Menu)
+                            .or_else(in_state(GameState::Ready))
+                            .or_else(in_state(GameState::Over)),
+                    ),
+                    open.run_if(in_state(GameState::Ready)),
+                    (browse.after(ButtonSystem), display_rows)
+                        .chain()
+                        .run_if(in_state(GameState::Controls)),
+                ),
+            )
+            .add_systems(OnEnter(GameState::Controls), spawn)
+            .add_systems(
+                OnExit(GameState::Controls),
+                despawn_recursive::<ControlsScreen>,
+            );
+    }
+}
+
+/// Every input device the controls are read from.
+#[derive(SystemParam)]
+struct Devices<'w> {
+    key: Res<'w, ButtonInput<KeyCode>>,
+    mouse: Res<'w, ButtonInput<MouseButton>>,
+    gamepad: Res<'w, ButtonInput<GamepadButton>>,
+    gamepads: Res<'w, Gamepads>,
+    touches: Res<'w, Touches>,
+}
+
+fn read(
+    devices: Devices,
+    window: Query<&Window, With<PrimaryWindow>>,
+    bindings: Res<Bindings>,
+    cursor: Res<ControlsCursor>,
+    typing: Option<Res<NameEntry>>,
+    mut controls: ResMut<Controls>,
+) {
+    let Devices {
+        key,
+        mouse,
+        gamepad,
+        gamepads,
+        touches,
+    } = devices;
+    let pressed = |button| {
+        gamepads
+            .iter()
+            .any(|id| gamepad.just_pressed(GamepadButton::new(id, button)))
+    };
+    let went = |binding: Binding, down: bool| match binding {
+        Binding::Key(code) => ternary!(down, key.just_pressed(code), key.just_released(code)),
+        Binding::Mouse(button) => ternary!(
+            down,
+            mouse.just_pressed(button),
+            mouse.just_released(button)
+        ),
+        Binding::Gamepad(button) => gamepads.iter().any(|id| {
+            let button = GamepadButton::new(id, button);
+
+            ternary!(
+                down,
+                gamepad.just_pressed(button),
+                gamepad.just_released(button)
+            )
+        }),
+    };
+    // Keys spell the name being typed and any input picks a new binding,
+    // neither should trigger an action.
+    let action = |action, down| {
+        !cursor.rebinding
+            && bindings.get(action).iter().any(|&binding| {
+                (typing.is_none() || !matches!(binding, Binding::Key(_))) && went(binding, down)
+            })
+    };
+
+    let mouse_cursor = window.get_single().ok().and_then(Window::cursor_position);
+    let click = mouse
+        .just_pressed(MouseButton::Left)
+        .then_some(mouse_cursor)
+        .flatten();
+    let touch = touches
+        .iter_just_pressed()
+        .next()
+        .map(|touch| touch.position());
+    let pointer = touch.or(click);
+    let touch_cursor = touches
+        .iter()
+        .chain(touches.iter_just_released())
+        .next()
+        .map(|touch| touch.position());
+
+    *controls = Controls {
+        flap: action(Action::Flap, true) || touch.is_some(),
+        flap_released: action(Action::Flap, false) || touches.any_just_released(),
+        pointer,
+        pointer_released: mouse.just_released(MouseButton::Left) || touches.any_just_released(),
+        cursor: touch_cursor.or(mouse_cursor),
+        pause: action(Action::Pause, true),
+        restart: action(Action::Restart, true),
+        mute: action(Action::Mute, true),
+        quit: action(Action::Quit, true),
+        back: action(Action::Back, true),
+        open_controls: action(Action::Controls, true),
+        open_audio: action(Action::Audio, true),
+        open_store: action(Action::Store, true),
+        open_gallery: action(Action::Gallery, true),
+        open_leaderboard: action(Action::Leaderboard, true),
+        difficulty: action(Action::Difficulty, true),
+        autopilot: action(Action::Autopilot, true),
+        fullscreen: action(Action::Fullscreen, true),
+        confirm: (typing.is_none() && key.any_just_pressed([KeyCode::Enter, KeyCode::Space]))
+            || pressed(GamepadButtonType::South),
+        up: key.just_pressed(KeyCode::ArrowUp) || pressed(GamepadButtonType::DPadUp),
+        down: key.just_pressed(KeyCode::ArrowDown) || pressed(GamepadButtonType::DPadDown),
+        left: key.just_pressed(KeyCode::ArrowLeft) || pressed(GamepadButtonType::DPadLeft),
+        right: key.just_pressed(KeyCode::ArrowRight) || pressed(GamepadButtonType::DPadRight),
+        any_pressed: key.get_just_pressed().next().is_some()
+            || mouse.get_just_pressed().next().is_some()
+            || gamepad.get_just_pressed().next().is_some()
+            || touches.any_just_pressed(),
+        any_held: key.get_pressed().next().is_some()
+            || mouse.get_pressed().next().is_some()
+            || gamepad.get_pressed().next().is_some()
+            || touches.iter().next().is_some(),
+    };
+}
+
+fn quit(controls: Res<Controls>, mut app_exit: EventWriter<AppExit>) {
+    if controls.quit {
+        app_exit.send(AppExit);
+    }
+}
+
+fn open(
+    controls: Res<Controls>,
+    mut settings_return: ResMut<SettingsReturn>,
+    mut next_state: ResMut<NextState<GameState>>,
+) {
+    if controls.open_controls {
+        settings_return.0 = GameState::Ready;
+        next_state.set(GameState::Controls);
+    }
+}
+
+fn spawn(mut commands: Commands, font_assets: Res<FontAssets>, mut cursor: ResMut<ControlsCursor>) {
+    *cursor = ControlsCursor::default();
+
+    let text_style = TextStyle {
+        font: font_assets.teko_bold.clone(),
+        font_size: 24f32,
+        ..default()
+    };
+
+    let screen = commands
+        .spawn((
+            SpriteBundle {
+                transform: Transform::from_xyz(0f32, 0f32, 0.9),
+                sprite: Sprite {
+                    color: Color::rgba(0f32, 0f32, 0f32, 0.75),
+                    custom_size: Some(Vec2::new(SCREEN_WIDTH, SCREEN_HEIGHT)),
+                    ..default()
+                },
+                ..default()
+            },
+            ControlsScreen,
+        ))
+        .with_children(|builder| {
+            builder.spawn(Text2dBundle {
+                text: Text::from_section(
+                    "CONTROLS",
+                    TextStyle {
+                        font_size: 48f32,
+                        ..text_style.clone()
+                    },
+                ),
+                transform: Transform::from_xyz(0f32, 215f32, 0.1),
+                ..default()
+            });
+
+            let rows = Action::ALL.map(Some).into_iter().chain([None]);
+
+            for (i, action) in rows.enumerate() {
+                builder.spawn((
+                    Text2dBundle {
+                        text: Text::from_sections([
+                            TextSection::new(
+                                "",
+                                TextStyle {
+                                    font_size: 20f32,
+                                    ..text_style.clone()
+                                },
+                            ),
+                            TextSection::new(
+                                "",
+                                TextStyle {
+                                    font_size: 14f32,
+                                    ..text_style.clone()
+                                },
+                            ),
+                        ])
+                        .with_justify(JustifyText::Center),
+                        transform: Transform::from_xyz(0f32, 178f32 - 24f32 * i as f32, 0.1),
+                        ..default()
+                    },
+                    ControlsRow(action),
+                ));
+            }
+
+            builder.spawn(Text2dBundle {
+                text: Text::from_section(
+                    "ENTER: BIND OR UNBIND   ESC: BACK OR CANCEL",
+                    TextStyle {
+                        font_size: 18f32,
+                        ..text_style.clone()
+                    },
+                ),
+                transform: Transform::from_xyz(0f32, -SCREEN_HEIGHT.half() + 24f32, 0.1),
+                ..default()
+            });
+        })
+        .id();
+
+    spawn_labelled(
+        &mut commands,
+        SpriteButton {
+            color: Color::rgb(0.98, 0.6, 0.14),
+        },
+        Vec2::new(114f32, 28f32),
+        Transform::from_xyz(0f32, -200f32, 0.1),
+        Text::from_section("BACK", text_style),
+    )
+    .insert(BackButton)
+    .set_parent(screen);
+}
+
+/// Move through the actions, or give the highlighted one
+/// the next key or button pressed, which unbinds it if it was already bound.
+/// The back binding, a touch or a click on the back button cancels the rebinding.
+fn browse(
+    devices: Devices,
+    controls: Res<Controls>,
+    mut back: Back,
+    settings_return: Res<SettingsReturn>,
+    mut cursor: ResMut<ControlsCursor>,
+    mut bindings: ResMut<Bindings>,
+    mut next_state: ResMut<NextState<GameState>>,
+) {
+    let Devices {
+        key,
+        mouse,
+        gamepad,
+        touches,
+        ..
+    } = devices;
+
+    if cursor.rebinding {
+        let pressed = key
+            .get_just_pressed()
+            .next()
+            .map(|code| Binding::Key(*code))
+            .or_else(|| {
+                mouse
+                    .get_just_pressed()
+                    .next()
+                    .map(|button| Binding::Mouse(*button))
+            })
+            .or_else(|| {
+                gamepad
+                    .get_just_pressed()
+                    .next()
+                    .map(|button| Binding::Gamepad(button.button_type))
+            });
+
+        let cancelled =
+            pressed.is_some_and(|binding| bindings.get(Action::Back).contains(&binding));
+
+        if cancelled || touches.any_just_pressed() || back.held() {
+            cursor.rebinding = false;
+            return;
+        }
+
+        if let (Some(binding), Some(action)) = (pressed, cursor.action()) {
+            bindings.toggle(action, binding);
+            cursor.rebinding = false;
+        }
+
+        return;
+    }
+
+    if back.pressed() || controls.open_controls {
+        next_state.set(settings_return.0);
+    }
+
+    if controls.down {
+        cursor.next();
+    }
+
+    if controls.up {
+        cursor.prev();
+    }
+
+    if controls.confirm {
+        match cursor.action() {
+            Some(_) => cursor.rebinding = true,
+            None => *bindings = Bindings::default(),
+        }
+    }
+}
+
+fn display_rows(
+    cursor: Res<ControlsCursor>,
+    bindings: Res<Bindings>,
+    mut rows: Query<(&ControlsRow, &mut Text)>,
+) {
+    if !cursor.is_changed() && !bindings.is_changed() {
+        return;
+    }
+
+    for (ControlsRow(action), mut text) in &mut rows {
+        let highlighted = *action == cursor.action();
+        let color = ternary!(highlighted, Color::WHITE, Color::GRAY);
+
+        let (name, bound) = match action {
+            Some(action) => {
+                let bound = match bindings.get(*action) {
+                    _ if highlighted && cursor.rebinding => "PRESS A KEY OR BUTTON".to_string(),
+                    [] => "NONE".to_string(),
+                    bound => bound
+                        .iter()
+                        .map(|binding| binding.name())
+                        .collect::<Vec<_>>()
+                        .join(", "),
+                };
+
+                (format!("{}  ", action.name()), bound)
+            }
+            None => ("RESET DEFAULTS".to_string(), String::new()),
+        };
+
+        text.sections[0].value = name;
+        text.sections[1].value = bound;
+
+        for section in &mut text.sections {
+            section.style.color = color;
+        }
+    }
+}
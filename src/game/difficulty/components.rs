@@ -0,0 +1,196 @@
+use crate::game::config::components::GameConfig;
+use bevy::{prelude::*, utils::HashMap};
+use serde::{Deserialize, Serialize};
+
+/// Difficulty picked on the ready screen.
+#[derive(Resource, Default, Clone, Copy, PartialEq, Eq, Hash, Debug, Serialize, Deserialize)]
+pub enum Preset {
+    Easy,
+    #[default]
+    Normal,
+    Hard,
+    /// Tuned by the player in `custom_difficulty.ron`.
+    Custom,
+}
+
+impl Preset {
+    pub const ALL: [Preset; 4] = [Preset::Easy, Preset::Normal, Preset::Hard, Preset::Custom];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Preset::Easy => "EASY",
+            Preset::Normal => "NORMAL",
+            Preset::Hard => "HARD",
+            Preset::Custom => "CUSTOM",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|preset| preset.name().eq_ignore_ascii_case(name))
+    }
+
+    pub fn next(self) -> Self {
+        let index = Self::ALL
+            .iter()
+            .position(|preset| *preset == self)
+            .unwrap_or_default();
+
+        Self::ALL[(index + 1) % Self::ALL.len()]
+    }
+
+    pub fn previous(self) -> Self {
+        let index = Self::ALL
+            .iter()
+            .position(|preset| *preset == self)
+            .unwrap_or_default();
+
+        Self::ALL[(index + Self::ALL.len() - 1) % Self::ALL.len()]
+    }
+}
+
+/// Value changing with the score, linearly between `(score, value)` points.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(transparent)]
+pub struct Curve(Vec<(usize, f32)>);
+
+impl Curve {
+    pub fn at(&self, score: usize) -> f32 {
+        let after = self.0.partition_point(|(point, _)| *point <= score);
+
+        match (self.0.get(after.wrapping_sub(1)), self.0.get(after)) {
+            (Some(&(from, start)), Some(&(to, end))) => {
+                start + (end - start) * (score - from) as f32 / (to - from) as f32
+            }
+            (Some(&(_, value)), None) | (None, Some(&(_, value))) => value,
+            (None, None) => 0f32,
+        }
+    }
+
+    /// Values at each point, the curve being made of straight lines in between.
+    fn corners(&self) -> impl Iterator<Item = f32> + '_ {
+        let empty = self.0.is_empty().then_some(0f32);
+
+        self.0.iter().map(|(_, value)| *value).chain(empty)
+    }
+}
+
+/// How the game gets harder as the score grows, for the selected preset.
+#[derive(Resource, Serialize, Deserialize, Clone, Debug)]
+pub struct DifficultyCurve {
+    pub gravity: f32,
+    pub flap: f32,
+    pub gap: Curve,
+    pub spacing: Curve,
+    pub speed: Curve,
+    pub lowest: Curve,
+    pub highest: Curve,
+}
+
+impl DifficultyCurve {
+    /// Check that pipes can be spawned at any score with these curves.
+    pub fn validate(&self) -> Result<(), String> {
+        let curves = [
+            ("gap", &self.gap),
+            ("spacing", &self.spacing),
+            ("speed", &self.speed),
+            ("lowest", &self.lowest),
+            ("highest", &self.highest),
+        ];
+
+        for (name, curve) in curves {
+            if curve.0.windows(2).any(|points| points[0].0 >= points[1].0) {
+                return Err(format!("the scores of `{name}` must be increasing"));
+            }
+        }
+
+        for (name, curve) in [("gap", &self.gap), ("spacing", &self.spacing)] {
+            if curve.corners().any(|value| value <= 0f32) {
+                return Err(format!("`{name}` must stay above 0"));
+            }
+        }
+
+        // Both are straight between their points, so checking these is enough.
+        let scores = self.lowest.0.iter().chain(&self.highest.0);
+
+        match scores
+            .map(|(score, _)| *score)
+            .chain([0])
+            .find(|score| self.lowest.at(*score) > self.highest.at(*score))
+        {
+            Some(score) => Err(format!("`lowest` is above `highest` at score {score}")),
+            None => Ok(()),
+        }
+    }
+
+    pub fn at(&self, score: usize) -> Difficulty {
+        Difficulty {
+            gravity: self.gravity,
+            flap: self.flap,
+            gap: self.gap.at(score),
+            spacing: self.spacing.at(score),
+            speed: self.speed.at(score),
+            lowest: self.lowest.at(score),
+            highest: self.highest.at(score),
+        }
+    }
+}
+
+/// Curves of every preset.
+#[derive(Resource, Clone)]
+pub struct Presets(pub HashMap<Preset, DifficultyCurve>);
+
+impl Presets {
+    /// Replace the curves of the presets found in `curves`.
+    pub fn update(&mut self, curves: &HashMap<Preset, DifficultyCurve>) {
+        self.0.extend(
+            curves
+                .iter()
+                .map(|(preset, curve)| (*preset, curve.clone())),
+        );
+
+        // Custom starts from the normal difficulty until the player tunes it.
+        if let Some(normal) = self.0.get(&Preset::Normal).cloned() {
+            self.0.entry(Preset::Custom).or_insert(normal);
+        }
+    }
+
+    pub fn get(&self, preset: Preset) -> &DifficultyCurve {
+        self.0
+            .get(&preset)
+            .or_else(|| self.0.get(&Preset::Normal))
+            .expect("Normal difficulty must be defined.")
+    }
+}
+
+impl Default for Presets {
+    fn default() -> Self {
+        let mut presets = Presets(HashMap::default());
+
+        presets.update(&GameConfig::default().difficulty);
+        presets
+    }
+}
+
+/// Difficulty at the current score.
+#[derive(Resource, Clone, Copy, Debug, PartialEq)]
+pub struct Difficulty {
+    /// Acceleration of the bird.
+    pub gravity: f32,
+    /// Velocity given by a flap (negative goes up).
+    pub flap: f32,
+    /// Opening between a pair of pipes.
+    pub gap: f32,
+    /// Distance between two pairs of pipes.
+    pub spacing: f32,
+    /// Multiplier of every scrolling speed.
+    pub speed: f32,
+    /// Lowest center of the lower pipe.
+    pub lowest: f32,
+    /// Highest center of the lower pipe.
+    pub highest: f32,
+}
+
+#[derive(Component)]
+pub(super) struct PresetLabel;
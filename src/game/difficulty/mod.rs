@@ -0,0 +1,119 @@
+automod::dir!(pub "src/game/difficulty");
+
+use super::{
+    config::components::GameConfig, controls::components::Controls, score::components::Score,
+    FontAssets, LaunchOptions, Simulation,
+};
+use crate::{despawn, BasicMath, GameState, SCREEN_HEIGHT};
+use bevy::prelude::*;
+use components::*;
+
+/// Difficulty logic.
+pub struct DifficultyPlugin;
+
+impl Plugin for DifficultyPlugin {
+    fn build(&self, app: &mut App) {
+        let presets = Presets::default();
+        let curve = presets.get(Preset::default()).clone();
+
+        app.insert_resource(curve.at(0))
+            .insert_resource(curve)
+            .insert_resource(presets)
+            .init_resource::<Preset>()
+            .add_systems(
+                OnExit(GameState::AssetLoading),
+                (override_preset, apply_preset).chain(),
+            )
+            .add_systems(OnEnter(GameState::Ready), spawn_label)
+            .add_systems(
+                Update,
+                (
+                    update_presets.run_if(resource_changed::<GameConfig>),
+                    (
+                        select,
+                        apply_preset.run_if(
+                            resource_changed::<Preset>.or_else(resource_changed::<Presets>),
+                        ),
+                        display_label,
+                    )
+                        .chain()
+                        .run_if(in_state(GameState::Ready)),
+                )
+                    .chain(),
+            )
+            .add_systems(
+                FixedUpdate,
+                progress
+                    .after(Simulation::Scoring)
+                    .run_if(in_state(GameState::Playing).and_then(resource_changed::<Score>)),
+            )
+            .add_systems(OnExit(GameState::Ready), despawn::<PresetLabel>)
+            .add_systems(OnExit(GameState::Over), reset)
+            .add_systems(
+                OnTransition {
+                    from: GameState::Paused,
+                    to: GameState::Ready,
+                },
+                reset,
+            );
+    }
+}
+
+/// Replays and command line runs bring their own difficulty.
+fn override_preset(options: Res<LaunchOptions>, mut preset: ResMut<Preset>) {
+    if let Some(difficulty) = options.difficulty {
+        *preset = difficulty;
+    }
+}
+
+fn apply_preset(
+    preset: Res<Preset>,
+    presets: Res<Presets>,
+    mut curve: ResMut<DifficultyCurve>,
+    mut difficulty: ResMut<Difficulty>,
+) {
+    *curve = presets.get(*preset).clone();
+    *difficulty = curve.at(0);
+}
+
+fn update_presets(config: Res<GameConfig>, mut presets: ResMut<Presets>) {
+    presets.update(&config.difficulty);
+}
+
+fn select(controls: Res<Controls>, mut preset: ResMut<Preset>) {
+    if controls.difficulty {
+        *preset = preset.next();
+    }
+}
+
+fn spawn_label(mut commands: Commands, font_assets: Res<FontAssets>) {
+    commands.spawn((
+        Text2dBundle {
+            text: Text::from_section(
+                "",
+                TextStyle {
+                    font: font_assets.teko_bold.clone(),
+                    font_size: 24f32,
+                    ..default()
+                },
+            ),
+            transform: Transform::from_xyz(0f32, 70f32 - SCREEN_HEIGHT.half(), 0.5),
+            ..default()
+        },
+        PresetLabel,
+    ));
+}
+
+fn display_label(preset: Res<Preset>, mut label: Query<&mut Text, With<PresetLabel>>) {
+    for mut text in &mut label {
+        text.sections[0].value = format!("D: {}", preset.name());
+    }
+}
+
+fn progress(score: Res<Score>, curve: Res<DifficultyCurve>, mut difficulty: ResMut<Difficulty>) {
+    *difficulty = curve.at(score.current);
+}
+
+fn reset(curve: Res<DifficultyCurve>, mut difficulty: ResMut<Difficulty>) {
+    *difficulty = curve.at(0);
+}
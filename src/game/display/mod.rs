@@ -0,0 +1,106 @@
+use super::controls::components::Controls;
+use crate::{ternary, BasicMath, SCREEN_HEIGHT, SCREEN_WIDTH};
+use bevy::{
+    prelude::*,
+    render::camera::{ScalingMode, Viewport},
+    window::{PrimaryWindow, WindowMode},
+};
+
+/// Display logic.
+///
+/// Keeps the playfield at `SCREEN_WIDTH` x `SCREEN_HEIGHT` game pixels in any window,
+/// scaled by a whole number of screen pixels and surrounded by black bars.
+pub struct DisplayPlugin;
+
+impl Plugin for DisplayPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(ClearColor(Color::BLACK))
+            .add_systems(Update, (press_fullscreen, letterbox).chain());
+    }
+}
+
+/// Where a window position lands in the world, taking the black bars into account.
+///
+/// Without a camera, like when running headless, the window is the screen.
+pub fn to_world(position: Vec2, camera: Option<(&Camera, &GlobalTransform)>) -> Option<Vec2> {
+    match camera {
+        Some((camera, transform)) => {
+            let origin = camera
+                .logical_viewport_rect()
+                .map_or(Vec2::ZERO, |viewport| viewport.min);
+
+            camera.viewport_to_world_2d(transform, position - origin)
+        }
+        None => Some(Vec2::new(
+            position.x - SCREEN_WIDTH.half(),
+            SCREEN_HEIGHT.half() - position.y,
+        )),
+    }
+}
+
+/// Switch the window between fullscreen and windowed.
+pub fn toggle_fullscreen(window: &mut Window) {
+    window.mode = ternary!(
+        window.mode == WindowMode::Windowed,
+        WindowMode::BorderlessFullscreen,
+        WindowMode::Windowed
+    );
+}
+
+fn press_fullscreen(controls: Res<Controls>, mut window: Query<&mut Window, With<PrimaryWindow>>) {
+    if !controls.fullscreen {
+        return;
+    }
+
+    if let Ok(mut window) = window.get_single_mut() {
+        toggle_fullscreen(&mut window);
+    }
+}
+
+/// Fit the playfield in the window whenever either of them changes,
+/// and the menus along with it.
+fn letterbox(
+    window: Query<Ref<Window>, With<PrimaryWindow>>,
+    mut cameras: Query<(&mut Camera, &mut OrthographicProjection)>,
+    mut ui_scale: ResMut<UiScale>,
+) {
+    let Ok(window) = window.get_single() else {
+        return;
+    };
+
+    let size = UVec2::new(window.physical_width(), window.physical_height());
+
+    // Nothing to draw in a minimized window.
+    if size.cmpeq(UVec2::ZERO).any() {
+        return;
+    }
+
+    let screen = Vec2::new(SCREEN_WIDTH, SCREEN_HEIGHT);
+    let fit = (size.as_vec2() / screen).min_element();
+    // Whole screen pixels per game pixel keep the sprites sharp,
+    // unless the window is too small to show even one.
+    let scale = ternary!(fit >= 1f32, fit.floor(), fit);
+    let viewport = (screen * scale).as_uvec2().max(UVec2::ONE).min(size);
+
+    if window.is_changed() {
+        // A pixel of the menus is a pixel of the playfield.
+        ui_scale.0 = scale / window.scale_factor();
+    }
+
+    for (mut camera, mut projection) in &mut cameras {
+        if !window.is_changed() && !camera.is_added() {
+            continue;
+        }
+
+        projection.scaling_mode = ScalingMode::Fixed {
+            width: SCREEN_WIDTH,
+            height: SCREEN_HEIGHT,
+        };
+
+        camera.viewport = Some(Viewport {
+            physical_position: (size - viewport) / 2,
+            physical_size: viewport,
+            ..default()
+        });
+    }
+}
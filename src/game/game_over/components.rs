@@ -1,6 +1,8 @@
-use bevy::prelude::Component;
+use crate::game::{config::components::MedalConfig, ImageAssets};
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
 
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug, Serialize, Deserialize)]
 pub enum MedalType {
     Bronze,
     Silver,
@@ -8,10 +10,41 @@ pub enum MedalType {
     Platinum,
 }
 
+impl MedalType {
+    /// Medal earned by finishing a run with `score`.
+    pub fn from_score(score: usize, thresholds: &MedalConfig) -> Option<Self> {
+        [
+            (thresholds.platinum, MedalType::Platinum),
+            (thresholds.gold, MedalType::Gold),
+            (thresholds.silver, MedalType::Silver),
+            (thresholds.bronze, MedalType::Bronze),
+        ]
+        .into_iter()
+        .find(|(threshold, _)| score >= *threshold)
+        .map(|(_, medal)| medal)
+    }
+
+    pub fn texture(self, image_assets: &ImageAssets) -> Handle<Image> {
+        match self {
+            MedalType::Bronze => image_assets.bronze_medal.clone(),
+            MedalType::Silver => image_assets.silver_medal.clone(),
+            MedalType::Gold => image_assets.gold_medal.clone(),
+            MedalType::Platinum => image_assets.platinum_medal.clone(),
+        }
+    }
+}
+
 #[derive(Component)]
 pub struct Medal(Option<MedalType>);
 
 impl Medal {
+    /// Scale the medal starts shrinking from.
+    pub const START_SCALE: f32 = 25f32;
+    /// Scale the medal ends at, filling its place on the scoreboard.
+    pub const END_SCALE: f32 = 1.225;
+    /// Scale lost per second.
+    pub const SHRINK_SPEED: f32 = 60f32;
+
     pub fn new(medal: Option<MedalType>) -> Self {
         Self(medal)
     }
@@ -24,25 +57,18 @@ impl Medal {
 #[derive(Component)]
 pub struct RestartButton;
 
+/// Button next to the restart one, opening the title menu.
 #[derive(Component)]
+pub struct MenuButton;
+
+#[derive(Component, Default)]
 pub struct Scoreboard {
     pub velocity: f32,
-    pub gravity: f32,
-}
-
-impl Default for Scoreboard {
-    fn default() -> Self {
-        Scoreboard {
-            gravity: 0.15,
-            velocity: 0f32,
-        }
-    }
 }
 
 #[derive(Component)]
 pub struct GameOverText {
     pub velocity: f32,
-    pub gravity: f32,
 
     /// Whether text should be bounced or not (default: true).
     pub bounce: bool,
@@ -51,7 +77,6 @@ pub struct GameOverText {
 impl Default for GameOverText {
     fn default() -> Self {
         GameOverText {
-            gravity: 0.1,
             velocity: 0f32,
             bounce: true,
         }
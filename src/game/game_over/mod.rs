@@ -3,14 +3,16 @@ automod::dir!(pub "src/game/game_over");
 use super::{
     audio::events::AudioEvent,
     bird::events::{DeathEvent, InTheHeaven},
+    button::{components::SpriteButton, spawn_labelled},
+    config::components::GameConfig,
+    leaderboard::components::NameEntry,
     score::components::Score,
-    AudioAssets, ImageAssets,
+    AudioAssets, FontAssets, ImageAssets,
 };
-use crate::GameState;
-use bevy::prelude::*;
+use crate::{despawn, despawn_recursive, GameState, Switcher};
+use bevy::{ecs::system::SystemParam, prelude::*};
 use components::*;
 use events::*;
-use flappybust::{despawn, Switcher};
 
 /// Game over logic.
 pub struct GameOverPlugin;
@@ -27,7 +29,8 @@ impl Plugin for GameOverPlugin {
                 Update,
                 (
                     scale_medal,
-                    move_scoreboard_up,
+                    // The scoreboard waits for the name of a new leaderboard entry.
+                    move_scoreboard_up.run_if(not(resource_exists::<NameEntry>)),
                     bounce_game_over_text,
                     display_restart_btn,
                 )
@@ -40,16 +43,22 @@ impl Plugin for GameOverPlugin {
                     despawn::<GameOverText>,
                     despawn::<Scoreboard>,
                     despawn::<RestartButton>,
+                    despawn_recursive::<MenuButton>,
                 ),
             );
     }
 }
 
-fn spawn_game_over(mut commands: Commands, asset_server: Res<AssetServer>) {
+fn spawn_game_over(
+    mut commands: Commands,
+    image_assets: Res<ImageAssets>,
+    font_assets: Res<FontAssets>,
+    config: Res<GameConfig>,
+) {
     commands.spawn((
         SpriteBundle {
-            transform: Transform::from_xyz(0f32, 351f32, 0.2),
-            texture: asset_server.load("images/game_over.png"),
+            transform: Transform::from_xyz(0f32, config.game_over.text_start, 0.2),
+            texture: image_assets.game_over.clone(),
             ..default()
         },
         GameOverText::default(),
@@ -57,8 +66,8 @@ fn spawn_game_over(mut commands: Commands, asset_server: Res<AssetServer>) {
 
     commands.spawn((
         SpriteBundle {
-            transform: Transform::from_xyz(0f32, -199f32, 0.2),
-            texture: asset_server.load("images/scoreboard.png"),
+            transform: Transform::from_xyz(0f32, config.game_over.scoreboard_start, 0.2),
+            texture: image_assets.scoreboard.clone(),
             ..default()
         },
         Scoreboard::default(),
@@ -66,17 +75,42 @@ fn spawn_game_over(mut commands: Commands, asset_server: Res<AssetServer>) {
 
     commands.spawn((
         SpriteBundle {
-            texture: asset_server.load("images/restart_btn.png"),
+            texture: image_assets.restart_btn.clone(),
             visibility: Visibility::Hidden,
-            transform: Transform::from_xyz(0f32, -35f32, 0.2),
+            transform: Transform::from_xyz(-60f32, -35f32, 0.2),
             ..default()
         },
+        SpriteButton::default(),
+        Interaction::default(),
         RestartButton,
     ));
+
+    let menu_btn = SpriteButton {
+        color: Color::rgb(0.98, 0.6, 0.14),
+    };
+
+    spawn_labelled(
+        &mut commands,
+        menu_btn,
+        Vec2::new(114f32, 40f32),
+        Transform::from_xyz(60f32, -35f32, 0.2),
+        Text::from_section(
+            "MENU",
+            TextStyle {
+                font: font_assets.teko_bold.clone(),
+                font_size: 32f32,
+                ..default()
+            },
+        ),
+    )
+    .insert((Visibility::Hidden, MenuButton));
 }
 
+/// Buttons shown once the bird has landed.
+type GameOverButtons = Or<(With<RestartButton>, With<MenuButton>)>;
+
 fn display_restart_btn(
-    mut restart_btn: Query<&mut Visibility, With<RestartButton>>,
+    mut buttons: Query<&mut Visibility, GameOverButtons>,
     in_the_heaven: EventReader<InTheHeaven>,
     mut restart_btn_event: EventWriter<RestartButtonDisplayed>,
 ) {
@@ -84,79 +118,77 @@ fn display_restart_btn(
         return;
     }
 
-    let mut visibility = restart_btn.single_mut();
-
-    if matches!(*visibility, Visibility::Hidden) {
-        visibility.on();
+    for mut visibility in &mut buttons {
+        if matches!(*visibility, Visibility::Hidden) {
+            visibility.on();
+        }
     }
 
     restart_btn_event.send_default();
 }
 
 fn bounce_game_over_text(
+    time: Res<Time>,
     mut game_over_text: Query<(&mut Transform, &mut GameOverText)>,
     mut game_over_text_event: EventWriter<GameOverTextDisplayed>,
+    config: Res<GameConfig>,
 ) {
     let (mut transform, mut game_over_text) = game_over_text.single_mut();
+    let config = &config.game_over;
 
-    game_over_text.velocity += game_over_text.gravity;
-    transform.translation.y -= game_over_text.velocity;
+    game_over_text.velocity += config.text_gravity * time.delta_seconds();
+    transform.translation.y -= game_over_text.velocity * time.delta_seconds();
 
-    if transform.translation.y < 156f32 {
+    if transform.translation.y < config.text_end {
         if game_over_text.bounce {
-            game_over_text.velocity *= -0.73;
+            game_over_text.velocity *= -config.text_bounce;
             game_over_text.bounce = false;
             return;
         }
 
-        transform.translation.y = 156f32;
+        transform.translation.y = config.text_end;
         game_over_text_event.send_default();
     }
 }
 
 fn move_scoreboard_up(
+    time: Res<Time>,
     mut scoreboard: Query<(&mut Transform, &mut Scoreboard)>,
     mut scoreboard_event: EventWriter<ScoreboardDisplayed>,
+    config: Res<GameConfig>,
 ) {
     let (mut transform, mut scoreboard) = scoreboard.single_mut();
+    let config = &config.game_over;
 
-    scoreboard.velocity += scoreboard.gravity;
+    scoreboard.velocity += config.scoreboard_gravity * time.delta_seconds();
 
-    transform.translation.y = (transform.translation.y + scoreboard.velocity).clamp(-199f32, 57f32);
+    transform.translation.y = (transform.translation.y
+        + scoreboard.velocity * time.delta_seconds())
+    .clamp(config.scoreboard_start, config.scoreboard_end);
 
-    if transform.translation.y == 57f32 {
+    if transform.translation.y == config.scoreboard_end {
         scoreboard_event.send_default();
     }
 }
 
-fn spawn_medal(mut commands: Commands, score: Res<Score>, image_assets: Res<ImageAssets>) {
-    let mut medal_name = None;
-
-    if score.current >= 10 && score.current < 20 {
-        medal_name = Some(MedalType::Bronze);
-    } else if score.current >= 20 && score.current < 30 {
-        medal_name = Some(MedalType::Silver);
-    } else if score.current >= 30 && score.current < 40 {
-        medal_name = Some(MedalType::Gold);
-    } else if score.current >= 40 {
-        medal_name = Some(MedalType::Platinum);
-    }
+fn spawn_medal(
+    mut commands: Commands,
+    score: Res<Score>,
+    image_assets: Res<ImageAssets>,
+    config: Res<GameConfig>,
+) {
+    let medal_name = MedalType::from_score(score.current, &config.medals);
 
     commands.spawn((
         SpriteBundle {
             transform: Transform {
                 translation: Vec3::new(-65f32, 47f32, 0.4),
-                scale: Vec3::new(25f32, 25f32, 0f32),
+                scale: Vec3::new(Medal::START_SCALE, Medal::START_SCALE, 0f32),
                 ..Transform::IDENTITY
             },
             visibility: Visibility::Hidden,
             texture: match medal_name {
-                Some(name) => match name {
-                    MedalType::Bronze => image_assets.bronze_medal.clone(),
-                    MedalType::Silver => image_assets.silver_medal.clone(),
-                    MedalType::Gold => image_assets.gold_medal.clone(),
-                    MedalType::Platinum => image_assets.platinum_medal.clone(),
-                },
+                Some(name) => name.texture(&image_assets),
                 None => Handle::Weak(AssetId::default()),
             },
             ..default()
@@ -165,19 +197,31 @@ fn spawn_medal(mut commands: Commands, score: Res<Score>, image_assets: Res<Imag
     ));
 }
 
+/// What the medal waits for before showing up.
+#[derive(SystemParam)]
+struct MedalCues<'w, 's> {
+    game_over_text_displayed: EventReader<'w, 's, GameOverTextDisplayed>,
+    death_event: EventReader<'w, 's, DeathEvent>,
+    scoreboard_displayed: EventReader<'w, 's, ScoreboardDisplayed>,
+}
+
+impl MedalCues<'_, '_> {
+    fn given(&self) -> bool {
+        !self.scoreboard_displayed.is_empty()
+            && !self.death_event.is_empty()
+            && !self.game_over_text_displayed.is_empty()
+    }
+}
+
 fn scale_medal(
+    time: Res<Time>,
     audio_assets: Res<AudioAssets>,
     mut medal: Query<(&mut Transform, &mut Visibility, &Medal)>,
     mut audio_event: EventWriter<AudioEvent>,
     mut medal_event: EventWriter<MedalDisplayed>,
-    game_over_text_displayed: EventReader<GameOverTextDisplayed>,
-    death_event: EventReader<DeathEvent>,
-    scoreboard_displayed: EventReader<ScoreboardDisplayed>,
+    cues: MedalCues,
 ) {
-    if scoreboard_displayed.is_empty()
-        || death_event.is_empty()
-        || game_over_text_displayed.is_empty()
-    {
+    if !cues.given() {
         return;
     }
 
@@ -192,21 +236,19 @@ fn scale_medal(
         visibility.on();
     }
 
-    // Scale both xy for circle.
-    let scale_direction = Vec3::X + Vec3::Y;
-    let unit_length = Vec3::ONE.length();
+    if transform.scale.x == Medal::END_SCALE {
+        medal_event.send_default();
+        return;
+    }
 
-    // Scale to orignal state.
-    transform.scale = (transform.scale - scale_direction).clamp_length_min(unit_length);
+    // Shrink both xy for circle, down to its own size.
+    let scale =
+        (transform.scale.x - Medal::SHRINK_SPEED * time.delta_seconds()).max(Medal::END_SCALE);
 
-    // Play audio if and only if the scale length is reached.
-    // (orignal + scale_direction) length.
-    if transform.scale == (Vec3::X + Vec3::Y + scale_direction) {
-        audio_event.send(AudioEvent::new(&audio_assets.ding, false));
-    }
+    transform.scale.x = scale;
+    transform.scale.y = scale;
 
-    // Reach the final end position.
-    if transform.scale == (transform.scale - scale_direction).clamp_length_min(unit_length) {
-        medal_event.send_default();
+    if scale == Medal::END_SCALE {
+        audio_event.send(AudioEvent::sfx(&audio_assets.ding));
     }
 }
@@ -0,0 +1,40 @@
+/// What an agent sees of the world before choosing to flap or not.
+///
+/// Distances are in pixels, positive upward and to the right.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct Observation {
+    pub bird_y: f32,
+    /// Negative while the bird goes up.
+    pub velocity: f32,
+    pub rotation: f32,
+    /// From the bird to the bottom edge of the upper pipe.
+    pub gap_top: f32,
+    /// From the bird to the top edge of the lower pipe.
+    pub gap_bottom: f32,
+    /// From the bird to the right edge of the next pipes.
+    pub gap_distance: f32,
+}
+
+impl Observation {
+    pub const SIZE: usize = 6;
+
+    pub fn values(&self) -> [f32; Self::SIZE] {
+        [
+            self.bird_y,
+            self.velocity,
+            self.rotation,
+            self.gap_top,
+            self.gap_bottom,
+            self.gap_distance,
+        ]
+    }
+}
+
+/// Outcome of a single simulation step.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct Step {
+    pub observation: Observation,
+    pub reward: f32,
+    /// The bird crashed, call `Gym::reset` to play again.
+    pub done: bool,
+}
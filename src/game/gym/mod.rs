@@ -0,0 +1,144 @@
+automod::dir!(pub "src/game/gym");
+
+use super::{
+    bird::{components::Bird, events::FlapAction},
+    difficulty::components::Preset,
+    headless::{components::DeathCause, headless_app},
+    interpolation::components::Interpolated,
+    pipe::components::Pipe,
+    replay::components::{Recording, Replay, RunTicks},
+    score::components::Score,
+    GameRng, LaunchOptions,
+};
+use crate::{ternary, BasicMath, GameState, SCREEN_WIDTH};
+use bevy::prelude::*;
+use components::*;
+
+/// Drives the gameplay one simulation step at a time
+/// for agents learning to play.
+///
+/// The world runs headless, see `HeadlessPlugin`.
+pub struct Gym {
+    app: App,
+}
+
+impl Gym {
+    /// Reward for each step the bird stays alive.
+    pub const SURVIVAL_REWARD: f32 = 0.1;
+    /// Reward for each pair of pipes passed.
+    pub const PIPE_REWARD: f32 = 1f32;
+
+    pub fn new() -> Self {
+        Gym {
+            app: headless_app(LaunchOptions::default()),
+        }
+    }
+
+    /// Start a new run, seeded with `seed` or a random one,
+    /// and return what the bird sees before its first step.
+    pub fn reset(&mut self, seed: Option<u64>) -> Observation {
+        self.app.world.resource_mut::<LaunchOptions>().seed = seed;
+
+        // Leaving the game over screen is what seeds a new run.
+        if self.state() == GameState::Playing {
+            self.go_to(GameState::Over);
+        }
+
+        if self.state() != GameState::Over {
+            while self.state() != GameState::Ready {
+                self.app.update();
+            }
+
+            self.go_to(GameState::Over);
+        }
+
+        self.go_to(GameState::Ready);
+        self.go_to(GameState::Playing);
+
+        self.observe()
+    }
+
+    /// Flap or not, then advance the world by one step.
+    pub fn step(&mut self, flap: bool) -> Step {
+        if self.done() {
+            return Step {
+                observation: self.observe(),
+                reward: 0f32,
+                done: true,
+            };
+        }
+
+        let score = self.score();
+
+        if flap {
+            self.app.world.send_event(FlapAction);
+        }
+
+        self.app.update();
+
+        let done = self.done();
+        let passed = self.score() - score;
+
+        Step {
+            observation: self.observe(),
+            reward: ternary!(done, 0f32, Self::SURVIVAL_REWARD) + passed as f32 * Self::PIPE_REWARD,
+            done,
+        }
+    }
+
+    /// Replay of the current run, to watch it or submit it to a score server.
+    pub fn replay(&self) -> Replay {
+        let world = &self.app.world;
+
+        Replay::record(
+            world.resource::<GameRng>().seed(),
+            *world.resource::<Preset>(),
+            world.resource::<RunTicks>(),
+            world.resource::<Recording>(),
+        )
+    }
+
+    fn state(&self) -> GameState {
+        *self.app.world.resource::<State<GameState>>().get()
+    }
+
+    fn go_to(&mut self, state: GameState) {
+        self.app
+            .world
+            .resource_mut::<NextState<GameState>>()
+            .set(state);
+        self.app.update();
+    }
+
+    fn done(&self) -> bool {
+        self.state() != GameState::Playing || self.app.world.resource::<DeathCause>().0.is_some()
+    }
+
+    fn score(&self) -> usize {
+        self.app.world.resource::<Score>().current
+    }
+
+    fn observe(&mut self) -> Observation {
+        let world = &mut self.app.world;
+        let (bird, bird_position) = world.query::<(&Bird, &Interpolated)>().single(world);
+        let (bird, bird_position) = (*bird, bird_position.current);
+
+        let mut pipes = world.query::<(&Pipe, &Interpolated)>();
+        let gap = Pipe::next_gap(pipes.iter(world), bird_position.x - Bird::WIDTH.half());
+
+        Observation {
+            bird_y: bird_position.y,
+            velocity: bird.velocity,
+            rotation: bird.rotation,
+            gap_top: gap.map_or(0f32, |gap| gap.top - bird_position.y),
+            gap_bottom: gap.map_or(0f32, |gap| gap.bottom - bird_position.y),
+            gap_distance: gap.map_or(SCREEN_WIDTH, |gap| gap.right - bird_position.x),
+        }
+    }
+}
+
+impl Default for Gym {
+    fn default() -> Self {
+        Self::new()
+    }
+}
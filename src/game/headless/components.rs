@@ -0,0 +1,20 @@
+use crate::game::{bird::components::BirdColor, collision::components::Obstacle, DateTime};
+use bevy::prelude::*;
+
+/// How a run played without a window ended.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RunReport {
+    pub score: usize,
+    /// What the bird crashed into, if it crashed at all.
+    pub death: Option<Obstacle>,
+    /// Simulation steps the run lasted.
+    pub ticks: u64,
+    /// Time of day the seed picked.
+    pub datetime: DateTime,
+    /// Color the bird was given, if it was spawned.
+    pub bird: Option<BirdColor>,
+}
+
+/// First obstacle hit during the current run.
+#[derive(Resource, Default)]
+pub struct DeathCause(pub Option<Obstacle>);
@@ -0,0 +1,185 @@
+automod::dir!(pub "src/game/headless");
+
+use super::{
+    bird::components::Bird,
+    collision::events::CollisionEvent,
+    difficulty::components::Preset,
+    replay::components::{Playback, Replay, RunTicks},
+    score::components::Score,
+    AudioAssets, DateTime, FontAssets, GamePlugin, GameplayPlugin, ImageAssets, LaunchOptions,
+    Simulation,
+};
+use crate::GameState;
+use bevy::{
+    input::InputPlugin,
+    prelude::*,
+    render::{
+        render_asset::RenderAssetUsages,
+        render_resource::{Extent3d, TextureDimension, TextureFormat},
+    },
+    time::TimeUpdateStrategy,
+    window::{ExitCondition, WindowPlugin},
+};
+use components::*;
+
+/// Headless logic.
+///
+/// Runs the gameplay on top of `MinimalPlugins`, without a window,
+/// a renderer or an audio device. Assets are replaced by empty handles,
+/// blank images for the buttons to be pressed, and each update advances
+/// the simulation by exactly one step.
+pub struct HeadlessPlugin;
+
+impl Plugin for HeadlessPlugin {
+    fn build(&self, app: &mut App) {
+        let step = Time::<Fixed>::from_hz(GamePlugin::TICK_RATE).timestep();
+        let mut images = Assets::<Image>::default();
+        let image_assets = placeholder_images(&mut images);
+
+        app.add_plugins((
+            MinimalPlugins,
+            InputPlugin,
+            TransformPlugin,
+            WindowPlugin {
+                primary_window: None,
+                exit_condition: ExitCondition::DontExit,
+                close_when_requested: false,
+            },
+        ))
+        .init_state::<GameState>()
+        .insert_resource(TimeUpdateStrategy::ManualDuration(step))
+        .insert_resource(image_assets)
+        .insert_resource(images)
+        .init_resource::<AudioAssets>()
+        .init_resource::<FontAssets>()
+        .init_resource::<DeathCause>()
+        .add_plugins(GameplayPlugin)
+        .add_systems(OnEnter(GameState::AssetLoading), skip_loading)
+        .add_systems(
+            OnTransition {
+                from: GameState::Ready,
+                to: GameState::Playing,
+            },
+            forget_death,
+        )
+        .add_systems(
+            FixedUpdate,
+            record_death
+                .after(Simulation::Collision)
+                .run_if(in_state(GameState::Playing)),
+        );
+    }
+}
+
+/// Longest run flown by the autopilot, ten minutes of play.
+pub const MAX_TICKS: u64 = 36_000;
+
+/// Play a replay until the bird dies or `max_ticks` have passed
+/// and report how the run went.
+pub fn simulate(replay: Replay, max_ticks: u64) -> RunReport {
+    let mut app = headless_app(LaunchOptions {
+        seed: Some(replay.seed),
+        difficulty: Some(replay.preset),
+        ..default()
+    });
+
+    app.insert_resource(Playback(replay));
+
+    play(&mut app, max_ticks)
+}
+
+/// Let the autopilot fly until it crashes or `max_ticks` have passed.
+pub fn autopilot(seed: u64, preset: Preset, max_ticks: u64) -> RunReport {
+    let mut app = headless_app(LaunchOptions {
+        seed: Some(seed),
+        difficulty: Some(preset),
+        autopilot: true,
+        ..default()
+    });
+
+    while *app.world.resource::<State<GameState>>() != GameState::Ready {
+        app.update();
+    }
+
+    app.world
+        .resource_mut::<NextState<GameState>>()
+        .set(GameState::Playing);
+
+    play(&mut app, max_ticks)
+}
+
+pub(crate) fn headless_app(options: LaunchOptions) -> App {
+    let mut app = App::new();
+
+    app.add_plugins(HeadlessPlugin).insert_resource(options);
+    app.finish();
+    app.cleanup();
+
+    app
+}
+
+fn play(app: &mut App, max_ticks: u64) -> RunReport {
+    while *app.world.resource::<State<GameState>>() != GameState::Over
+        && app.world.resource::<RunTicks>().playing < max_ticks
+    {
+        app.update();
+    }
+
+    let bird = app
+        .world
+        .query::<&Bird>()
+        .iter(&app.world)
+        .next()
+        .map(|bird| bird.color);
+
+    RunReport {
+        score: app.world.resource::<Score>().current,
+        death: app.world.resource::<DeathCause>().0,
+        ticks: app.world.resource::<RunTicks>().playing,
+        datetime: *app.world.resource::<DateTime>(),
+        bird,
+    }
+}
+
+/// Birds must still have their three animation frames,
+/// and buttons their size to be hit-tested against.
+fn placeholder_images(images: &mut Assets<Image>) -> ImageAssets {
+    let frames = vec![Handle::default(); 3];
+    let mut blank = |width, height| {
+        images.add(Image::new_fill(
+            Extent3d {
+                width,
+                height,
+                depth_or_array_layers: 1,
+            },
+            TextureDimension::D2,
+            &[0; 4],
+            TextureFormat::Rgba8UnormSrgb,
+            RenderAssetUsages::default(),
+        ))
+    };
+
+    ImageAssets {
+        restart_btn: blank(114, 40),
+        blue_birds: frames.clone(),
+        red_birds: frames.clone(),
+        yellow_birds: frames.clone(),
+        purple_birds: frames.clone(),
+        green_birds: frames,
+        ..default()
+    }
+}
+
+fn skip_loading(mut next_state: ResMut<NextState<GameState>>) {
+    next_state.set(GameState::Ready);
+}
+
+fn forget_death(mut death: ResMut<DeathCause>) {
+    death.0 = None;
+}
+
+fn record_death(mut collision_event: EventReader<CollisionEvent>, mut death: ResMut<DeathCause>) {
+    if let Some(CollisionEvent(obstacle)) = collision_event.read().next() {
+        death.0.get_or_insert(*obstacle);
+    }
+}
@@ -0,0 +1,27 @@
+use bevy::prelude::*;
+
+/// Translation simulated on fixed timesteps.
+///
+/// The rendered [`Transform`] is interpolated between the two latest steps
+/// so movement stays smooth whatever the refresh rate is.
+#[derive(Component, Clone, Copy, Default)]
+pub struct Interpolated {
+    pub previous: Vec3,
+    pub current: Vec3,
+}
+
+impl Interpolated {
+    pub fn new(translation: Vec3) -> Self {
+        Interpolated {
+            previous: translation,
+            current: translation,
+        }
+    }
+
+    /// Move both steps at once so the jump is not interpolated,
+    /// e.g. when wrapping around the screen.
+    pub fn shift(&mut self, offset: Vec3) {
+        self.previous += offset;
+        self.current += offset;
+    }
+}
@@ -0,0 +1,33 @@
+automod::dir!(pub "src/game/interpolation");
+
+use bevy::{prelude::*, transform::TransformSystem};
+use components::Interpolated;
+
+/// Interpolation logic.
+pub struct InterpolationPlugin;
+
+impl Plugin for InterpolationPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(FixedFirst, store_previous).add_systems(
+            PostUpdate,
+            interpolate.before(TransformSystem::TransformPropagate),
+        );
+    }
+}
+
+fn store_previous(mut positions: Query<&mut Interpolated>) {
+    for mut position in &mut positions {
+        position.previous = position.current;
+    }
+}
+
+fn interpolate(
+    fixed_time: Res<Time<Fixed>>,
+    mut positions: Query<(&Interpolated, &mut Transform)>,
+) {
+    let alpha = fixed_time.overstep_fraction();
+
+    for (position, mut transform) in &mut positions {
+        transform.translation = position.previous.lerp(position.current, alpha);
+    }
+}
@@ -0,0 +1,191 @@
+use crate::game::{
+    bird::components::BirdColor, difficulty::components::Preset, game_over::components::MedalType,
+    DateTime,
+};
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// A run kept on the leaderboard.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Entry {
+    pub name: String,
+    pub score: usize,
+    /// Day the run was played, as `YYYY-MM-DD`.
+    pub date: String,
+    pub bird: BirdColor,
+    pub datetime: DateTime,
+    pub medal: Option<MedalType>,
+    pub preset: Preset,
+}
+
+/// Best runs played on this machine, each difficulty keeping its own table.
+#[derive(Resource, Default, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Leaderboard {
+    /// Sorted from the best score to the worst.
+    pub entries: Vec<Entry>,
+    /// Name typed for the last entry, offered again for the next one.
+    pub last_name: String,
+}
+
+impl Leaderboard {
+    /// Entries kept for each difficulty.
+    pub const SIZE: usize = 10;
+
+    pub fn table(&self, preset: Preset) -> impl Iterator<Item = &Entry> {
+        self.entries
+            .iter()
+            .filter(move |entry| entry.preset == preset)
+    }
+
+    pub fn best(&self, preset: Preset) -> usize {
+        self.table(preset).next().map_or(0, |entry| entry.score)
+    }
+
+    /// Best score on any difficulty.
+    pub fn best_overall(&self) -> usize {
+        Preset::ALL
+            .into_iter()
+            .map(|preset| self.best(preset))
+            .max()
+            .unwrap_or_default()
+    }
+
+    /// Whether a run ending with `score` makes it into the table.
+    pub fn qualifies(&self, preset: Preset, score: usize) -> bool {
+        score > 0
+            && self
+                .table(preset)
+                .nth(Self::SIZE - 1)
+                .is_none_or(|last| score > last.score)
+    }
+
+    /// Rank a run ending with `score` would get, starting from 0.
+    pub fn rank(&self, preset: Preset, score: usize) -> usize {
+        self.table(preset)
+            .take_while(|entry| entry.score >= score)
+            .count()
+    }
+
+    /// Add an entry below the ones with the same score,
+    /// dropping the worst one of its table when it is full.
+    pub fn insert(&mut self, entry: Entry) {
+        let preset = entry.preset;
+        let index = self
+            .entries
+            .partition_point(|other| other.score >= entry.score);
+
+        self.entries.insert(index, entry);
+
+        let mut kept = 0;
+
+        self.entries.retain(|other| {
+            if other.preset != preset {
+                return true;
+            }
+
+            kept += 1;
+            kept <= Self::SIZE
+        });
+    }
+}
+
+/// Name being typed after a run that made it into the leaderboard.
+#[derive(Resource, Default)]
+pub struct NameEntry(pub String);
+
+impl NameEntry {
+    pub const MAX_LENGTH: usize = 10;
+    /// Name given to an entry left blank.
+    pub const DEFAULT: &'static str = "PLAYER";
+
+    /// Keep the characters the font can draw, in upper case,
+    /// without leading spaces.
+    pub fn push_str(&mut self, text: &str) {
+        for c in text.chars() {
+            if self.0.chars().count() >= Self::MAX_LENGTH {
+                return;
+            }
+
+            if c.is_ascii_alphanumeric() || (c == ' ' && !self.0.is_empty()) {
+                self.0.push(c.to_ascii_uppercase());
+            }
+        }
+    }
+
+    pub fn name(&self) -> String {
+        match self.0.trim() {
+            "" => Self::DEFAULT.to_string(),
+            name => name.to_string(),
+        }
+    }
+}
+
+/// Best runs of every player, fetched from the score server.
+#[derive(Resource, Default)]
+pub struct GlobalLeaderboard(pub Leaderboard);
+
+/// Table shown on the leaderboard screen.
+#[derive(Resource, Default)]
+pub(super) struct ShownTable {
+    pub preset: Preset,
+    pub global: bool,
+}
+
+#[derive(Component)]
+pub(super) struct NamePrompt;
+
+#[derive(Component)]
+pub(super) struct NameField;
+
+#[derive(Component)]
+pub(super) struct LeaderboardScreen;
+
+#[derive(Component)]
+pub(super) struct TableTitle;
+
+#[derive(Component)]
+pub(super) struct TableRow;
+
+/// Today in the `YYYY-MM-DD` format.
+#[cfg(not(target_arch = "wasm32"))]
+pub(super) fn today() -> String {
+    use std::time::{SystemTime, UNIX_EPOCH};
+
+    let days = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |elapsed| elapsed.as_secs() / 86400);
+    let (year, month, day) = civil_from_days(days as i64);
+
+    format!("{year:04}-{month:02}-{day:02}")
+}
+
+/// Today in the `YYYY-MM-DD` format.
+#[cfg(target_arch = "wasm32")]
+pub(super) fn today() -> String {
+    let date = js_sys::Date::new_0();
+
+    format!(
+        "{:04}-{:02}-{:02}",
+        date.get_full_year(),
+        date.get_month() + 1,
+        date.get_date()
+    )
+}
+
+/// Gregorian date of a number of days since 1970-01-01,
+/// from Howard Hinnant's `civil_from_days`.
+#[cfg(not(target_arch = "wasm32"))]
+fn civil_from_days(days: i64) -> (i64, i64, i64) {
+    let z = days + 719468;
+    let era = z.div_euclid(146097);
+    let doe = z.rem_euclid(146097);
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+
+    (year, month, day)
+}
@@ -0,0 +1,10 @@
+use super::components::Entry;
+use bevy::ecs::event::Event;
+
+/// Event triggers when the player confirms the name of a new leaderboard entry.
+#[derive(Event, Default)]
+pub struct NameEntered;
+
+/// Event triggers when a run is added to the leaderboard.
+#[derive(Event)]
+pub struct EntryAdded(pub Entry);
@@ -0,0 +1,494 @@
+automod::dir!(pub "src/game/leaderboard");
+
+use super::{
+    autopilot,
+    bird::components::Bird,
+    button::{
+        components::{BackButton, SpriteButton},
+        spawn_labelled, Back, ButtonSystem,
+    },
+    config::components::GameConfig,
+    controls::components::Controls,
+    difficulty::components::Preset,
+    game_over::components::MedalType,
+    menu::components::SettingsReturn,
+    replay::components::Playback,
+    score::components::Score,
+    DateTime, FontAssets, ImageAssets,
+};
+use crate::{despawn_recursive, ternary, BasicMath, GameState, SCREEN_HEIGHT, SCREEN_WIDTH};
+use bevy::{
+    ecs::system::SystemParam,
+    input::{
+        keyboard::{Key, KeyboardInput},
+        ButtonState,
+    },
+    prelude::*,
+    sprite::Anchor,
+};
+use components::*;
+use events::*;
+
+/// Leaderboard logic.
+pub struct LeaderboardPlugin;
+
+impl Plugin for LeaderboardPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<NameEntered>()
+            .add_event::<EntryAdded>()
+            .init_resource::<Leaderboard>()
+            .init_resource::<ShownTable>()
+            .add_systems(
+                OnEnter(GameState::Over),
+                prompt_name
+                    .run_if(not(autopilot::has_flown).and_then(not(resource_exists::<Playback>))),
+            )
+            .add_systems(
+                Update,
+                (
+                    type_name.run_if(resource_exists::<NameEntry>),
+                    (enter_name, despawn_recursive::<NamePrompt>)
+                        .chain()
+                        .run_if(on_event::<NameEntered>()),
+                    open_table.run_if(in_state(GameState::Ready)),
+                    (browse_table.after(ButtonSystem), display_table)
+                        .chain()
+                        .run_if(in_state(GameState::Leaderboard)),
+                ),
+            )
+            .add_systems(OnEnter(GameState::Leaderboard), spawn_table)
+            .add_systems(
+                OnExit(GameState::Leaderboard),
+                despawn_recursive::<LeaderboardScreen>,
+            )
+            .add_systems(
+                OnExit(GameState::Over),
+                (despawn_recursive::<NamePrompt>, forget_name),
+            );
+    }
+}
+
+/// Ask for a name when the run made it into the leaderboard.
+fn prompt_name(
+    mut commands: Commands,
+    leaderboard: Res<Leaderboard>,
+    score: Res<Score>,
+    preset: Res<Preset>,
+    font_assets: Res<FontAssets>,
+) {
+    if !leaderboard.qualifies(*preset, score.current) {
+        return;
+    }
+
+    let mut entry = NameEntry::default();
+    entry.push_str(&leaderboard.last_name);
+
+    let text_style = TextStyle {
+        font: font_assets.teko_bold.clone(),
+        font_size: 20f32,
+        ..default()
+    };
+
+    commands
+        .spawn((
+            SpriteBundle {
+                transform: Transform::from_xyz(0f32, 40f32, 0.95),
+                sprite: Sprite {
+                    color: Color::rgba(0f32, 0f32, 0f32, 0.75),
+                    custom_size: Some(Vec2::new(SCREEN_WIDTH - 48f32, 132f32)),
+                    ..default()
+                },
+                ..default()
+            },
+            NamePrompt,
+        ))
+        .with_children(|builder| {
+            builder.spawn(Text2dBundle {
+                text: Text::from_section(
+                    format!(
+                        "NEW RECORD #{}",
+                        leaderboard.rank(*preset, score.current) + 1
+                    ),
+                    TextStyle {
+                        font_size: 32f32,
+                        color: Color::GOLD,
+                        ..text_style.clone()
+                    },
+                ),
+                transform: Transform::from_xyz(0f32, 42f32, 0.1),
+                ..default()
+            });
+
+            builder.spawn(Text2dBundle {
+                text: Text::from_section("ENTER YOUR NAME", text_style.clone()),
+                transform: Transform::from_xyz(0f32, 14f32, 0.1),
+                ..default()
+            });
+
+            builder.spawn((
+                Text2dBundle {
+                    text: Text::from_section(
+                        format!("{}_", entry.0),
+                        TextStyle {
+                            font_size: 32f32,
+                            ..text_style.clone()
+                        },
+                    ),
+                    transform: Transform::from_xyz(0f32, -16f32, 0.1),
+                    ..default()
+                },
+                NameField,
+            ));
+
+            builder.spawn(Text2dBundle {
+                text: Text::from_section(
+                    "ENTER OR TAP: OK",
+                    TextStyle {
+                        font_size: 16f32,
+                        ..text_style
+                    },
+                ),
+                transform: Transform::from_xyz(0f32, -48f32, 0.1),
+                ..default()
+            });
+        });
+
+    commands.insert_resource(entry);
+}
+
+fn type_name(
+    mut keyboard_input: EventReader<KeyboardInput>,
+    controls: Res<Controls>,
+    mut entry: ResMut<NameEntry>,
+    mut name_field: Query<&mut Text, With<NameField>>,
+    mut name_entered: EventWriter<NameEntered>,
+) {
+    for input in keyboard_input.read() {
+        if input.state != ButtonState::Pressed {
+            continue;
+        }
+
+        match &input.logical_key {
+            Key::Character(text) => entry.push_str(text),
+            Key::Space => entry.push_str(" "),
+            Key::Backspace => {
+                entry.0.pop();
+            }
+            Key::Enter => {
+                name_entered.send_default();
+            }
+            _ => {}
+        }
+    }
+
+    // Without a keyboard, the name stays the default one. The prompt ignores
+    // the input of the frame it opened on, which may be the fatal flap.
+    let pressed = controls.confirm || controls.back || controls.pointer.is_some();
+
+    if pressed && !entry.is_added() {
+        name_entered.send_default();
+    }
+
+    if !entry.is_changed() {
+        return;
+    }
+
+    for mut text in &mut name_field {
+        text.sections[0].value = format!("{}_", entry.0);
+    }
+}
+
+/// What the leaderboard keeps of the run that just ended.
+#[derive(SystemParam)]
+struct FinishedRun<'w, 's> {
+    score: Res<'w, Score>,
+    preset: Res<'w, Preset>,
+    datetime: Res<'w, DateTime>,
+    config: Res<'w, GameConfig>,
+    bird: Query<'w, 's, &'static Bird>,
+}
+
+fn enter_name(
+    mut commands: Commands,
+    entry: Res<NameEntry>,
+    mut leaderboard: ResMut<Leaderboard>,
+    run: FinishedRun,
+    mut entry_added: EventWriter<EntryAdded>,
+) {
+    let Ok(bird) = run.bird.get_single() else {
+        return;
+    };
+    let added = Entry {
+        name: entry.name(),
+        score: run.score.current,
+        date: today(),
+        bird: bird.color,
+        datetime: *run.datetime,
+        medal: MedalType::from_score(run.score.current, &run.config.medals),
+        preset: *run.preset,
+    };
+
+    leaderboard.last_name = added.name.clone();
+    leaderboard.insert(added.clone());
+    entry_added.send(EntryAdded(added));
+
+    commands.remove_resource::<NameEntry>();
+}
+
+fn forget_name(mut commands: Commands) {
+    commands.remove_resource::<NameEntry>();
+}
+
+fn open_table(
+    controls: Res<Controls>,
+    mut settings_return: ResMut<SettingsReturn>,
+    mut next_state: ResMut<NextState<GameState>>,
+) {
+    if controls.open_leaderboard {
+        settings_return.0 = GameState::Ready;
+        next_state.set(GameState::Leaderboard);
+    }
+}
+
+fn spawn_table(
+    mut commands: Commands,
+    font_assets: Res<FontAssets>,
+    preset: Res<Preset>,
+    mut shown: ResMut<ShownTable>,
+) {
+    shown.preset = *preset;
+
+    let text_style = TextStyle {
+        font: font_assets.teko_bold.clone(),
+        font_size: 24f32,
+        ..default()
+    };
+
+    let screen = commands
+        .spawn((
+            SpriteBundle {
+                transform: Transform::from_xyz(0f32, 0f32, 0.9),
+                sprite: Sprite {
+                    color: Color::rgba(0f32, 0f32, 0f32, 0.75),
+                    custom_size: Some(Vec2::new(SCREEN_WIDTH, SCREEN_HEIGHT)),
+                    ..default()
+                },
+                ..default()
+            },
+            LeaderboardScreen,
+        ))
+        .with_children(|builder| {
+            builder.spawn(Text2dBundle {
+                text: Text::from_section(
+                    "LEADERBOARD",
+                    TextStyle {
+                        font_size: 48f32,
+                        ..text_style.clone()
+                    },
+                ),
+                transform: Transform::from_xyz(0f32, 215f32, 0.1),
+                ..default()
+            });
+
+            builder.spawn((
+                Text2dBundle {
+                    text: Text::from_section("", text_style.clone()),
+                    transform: Transform::from_xyz(0f32, 178f32, 0.1),
+                    ..default()
+                },
+                TableTitle,
+            ));
+
+            builder.spawn(Text2dBundle {
+                text: Text::from_section(
+                    "ARROWS: TABLE   ESC: BACK",
+                    TextStyle {
+                        font_size: 18f32,
+                        ..text_style.clone()
+                    },
+                ),
+                transform: Transform::from_xyz(0f32, -SCREEN_HEIGHT.half() + 24f32, 0.1),
+                ..default()
+            });
+        })
+        .id();
+
+    spawn_labelled(
+        &mut commands,
+        SpriteButton {
+            color: Color::rgb(0.98, 0.6, 0.14),
+        },
+        Vec2::new(114f32, 28f32),
+        Transform::from_xyz(0f32, -200f32, 0.1),
+        Text::from_section("BACK", text_style),
+    )
+    .insert(BackButton)
+    .set_parent(screen);
+}
+
+fn browse_table(
+    controls: Res<Controls>,
+    mut back: Back,
+    settings_return: Res<SettingsReturn>,
+    mut shown: ResMut<ShownTable>,
+    global: Option<Res<GlobalLeaderboard>>,
+    mut next_state: ResMut<NextState<GameState>>,
+) {
+    if back.pressed() || controls.open_leaderboard {
+        next_state.set(settings_return.0);
+    }
+
+    if controls.right {
+        shown.preset = shown.preset.next();
+    }
+
+    if controls.left {
+        shown.preset = shown.preset.previous();
+    }
+
+    // The global table only exists when a score server is set.
+    if controls.up || controls.down {
+        shown.global = !shown.global && global.is_some();
+    }
+}
+
+/// The tables that can be shown, the global one only with a score server.
+#[derive(SystemParam)]
+struct Tables<'w> {
+    local: Res<'w, Leaderboard>,
+    global: Option<Res<'w, GlobalLeaderboard>>,
+}
+
+/// Assets the table rows are drawn with.
+#[derive(SystemParam)]
+struct RowAssets<'w> {
+    fonts: Res<'w, FontAssets>,
+    images: Res<'w, ImageAssets>,
+}
+
+fn display_table(
+    mut commands: Commands,
+    shown: Res<ShownTable>,
+    tables: Tables,
+    assets: RowAssets,
+    screen: Query<Entity, With<LeaderboardScreen>>,
+    rows: Query<Entity, With<TableRow>>,
+    mut title: Query<&mut Text, With<TableTitle>>,
+) {
+    if !shown.is_changed()
+        && !tables
+            .global
+            .as_ref()
+            .is_some_and(|global| global.is_changed())
+    {
+        return;
+    }
+
+    let Ok(screen) = screen.get_single() else {
+        return;
+    };
+
+    for row in &rows {
+        commands.entity(row).despawn_recursive();
+    }
+
+    let leaderboard = match &tables.global {
+        Some(global) if shown.global => &global.0,
+        _ => &*tables.local,
+    };
+
+    for mut text in &mut title {
+        text.sections[0].value = match tables.global {
+            Some(_) => format!(
+                "{} {}",
+                ternary!(shown.global, "GLOBAL", "LOCAL"),
+                shown.preset.name()
+            ),
+            None => shown.preset.name().to_string(),
+        };
+    }
+
+    let text_style = TextStyle {
+        font: assets.fonts.teko_bold.clone(),
+        font_size: 24f32,
+        ..default()
+    };
+
+    commands.entity(screen).with_children(|builder| {
+        if leaderboard.table(shown.preset).next().is_none() {
+            builder.spawn((
+                Text2dBundle {
+                    text: Text::from_section("NO RUNS YET", text_style.clone()),
+                    transform: Transform::from_xyz(0f32, 0f32, 0.1),
+                    ..default()
+                },
+                TableRow,
+            ));
+        }
+
+        for (rank, entry) in leaderboard.table(shown.preset).enumerate() {
+            let y = 140f32 - 34f32 * rank as f32;
+            let datetime = match entry.datetime {
+                DateTime::Day => "DAY",
+                DateTime::Night => "NIGHT",
+            };
+
+            builder
+                .spawn((
+                    SpatialBundle::from_transform(Transform::from_xyz(0f32, y, 0.1)),
+                    TableRow,
+                ))
+                .with_children(|builder| {
+                    builder.spawn(Text2dBundle {
+                        text: Text::from_section((rank + 1).to_string(), text_style.clone()),
+                        transform: Transform::from_xyz(-118f32, 0f32, 0f32),
+                        ..default()
+                    });
+
+                    builder.spawn(SpriteBundle {
+                        texture: entry.bird.frames(&assets.images)[0].clone(),
+                        transform: Transform::from_xyz(-96f32, 0f32, 0f32)
+                            .with_scale(Vec3::splat(0.75)),
+                        ..default()
+                    });
+
+                    builder.spawn(Text2dBundle {
+                        text: Text::from_section(entry.name.clone(), text_style.clone()),
+                        text_anchor: Anchor::CenterLeft,
+                        transform: Transform::from_xyz(-78f32, 4f32, 0f32),
+                        ..default()
+                    });
+
+                    builder.spawn(Text2dBundle {
+                        text: Text::from_section(
+                            format!("{}  {datetime}", entry.date),
+                            TextStyle {
+                                font_size: 14f32,
+                                color: Color::GRAY,
+                                ..text_style.clone()
+                            },
+                        ),
+                        text_anchor: Anchor::CenterLeft,
+                        transform: Transform::from_xyz(-78f32, -11f32, 0f32),
+                        ..default()
+                    });
+
+                    builder.spawn(Text2dBundle {
+                        text: Text::from_section(entry.score.to_string(), text_style.clone()),
+                        text_anchor: Anchor::CenterRight,
+                        transform: Transform::from_xyz(88f32, 0f32, 0f32),
+                        ..default()
+                    });
+
+                    if let Some(medal) = entry.medal {
+                        builder.spawn(SpriteBundle {
+                            texture: medal.texture(&assets.images),
+                            transform: Transform::from_xyz(112f32, 0f32, 0f32)
+                                .with_scale(Vec3::splat(0.5)),
+                            ..default()
+                        });
+                    }
+                });
+        }
+    });
+}
@@ -0,0 +1,107 @@
+use crate::GameState;
+use bevy::prelude::*;
+
+/// Page of the title menu being shown.
+#[derive(Resource, Clone, Copy, Default, PartialEq, Eq, Debug)]
+pub enum MenuPage {
+    #[default]
+    Main,
+    Settings,
+    Stats,
+    Credits,
+}
+
+impl MenuPage {
+    pub fn items(self) -> &'static [MenuItem] {
+        match self {
+            MenuPage::Main => &[
+                MenuItem::Play,
+                MenuItem::Store,
+                MenuItem::Settings,
+                MenuItem::Stats,
+                MenuItem::Credits,
+                MenuItem::Quit,
+            ],
+            MenuPage::Settings => &[
+                MenuItem::Audio,
+                MenuItem::Controls,
+                MenuItem::Display,
+                MenuItem::Difficulty,
+                MenuItem::Back,
+            ],
+            MenuPage::Stats => &[MenuItem::Leaderboard, MenuItem::Gallery, MenuItem::Back],
+            MenuPage::Credits => &[MenuItem::Back],
+        }
+    }
+
+    pub fn title(self) -> &'static str {
+        match self {
+            MenuPage::Main => "FLAPPYBUST",
+            MenuPage::Settings => "SETTINGS",
+            MenuPage::Stats => "STATS",
+            MenuPage::Credits => "CREDITS",
+        }
+    }
+}
+
+/// Button of a menu page.
+#[derive(Component, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum MenuItem {
+    Play,
+    Store,
+    Settings,
+    Stats,
+    Credits,
+    Quit,
+    Leaderboard,
+    /// Browse the achievements.
+    Gallery,
+    Audio,
+    Controls,
+    /// Switch between a window and fullscreen.
+    Display,
+    /// Go through the difficulty presets.
+    Difficulty,
+    Back,
+}
+
+impl MenuItem {
+    pub fn name(self) -> &'static str {
+        match self {
+            MenuItem::Play => "PLAY",
+            MenuItem::Store => "STORE",
+            MenuItem::Settings => "SETTINGS",
+            MenuItem::Stats => "STATS",
+            MenuItem::Credits => "CREDITS",
+            MenuItem::Quit => "QUIT",
+            MenuItem::Leaderboard => "LEADERBOARD",
+            MenuItem::Gallery => "ACHIEVEMENTS",
+            MenuItem::Audio => "AUDIO",
+            MenuItem::Controls => "CONTROLS",
+            MenuItem::Display => "FULLSCREEN",
+            MenuItem::Difficulty => "DIFFICULTY",
+            MenuItem::Back => "BACK",
+        }
+    }
+}
+
+/// Index of the highlighted item of the shown page.
+#[derive(Resource, Default)]
+pub(super) struct MenuCursor(pub usize);
+
+/// State a screen opened from both the ready screen and the menu goes back to when it is left.
+#[derive(Resource, Clone, Copy)]
+pub struct SettingsReturn(pub GameState);
+
+impl Default for SettingsReturn {
+    fn default() -> Self {
+        SettingsReturn(GameState::Ready)
+    }
+}
+
+#[derive(Component)]
+pub(super) struct MenuScreen;
+
+/// Text of a menu item, showing the current setting if it has one.
+#[derive(Component)]
+pub(super) struct MenuLabel(pub MenuItem);
@@ -0,0 +1,6 @@
+use super::components::MenuItem;
+use bevy::prelude::*;
+
+/// Event triggers when a menu item is clicked, touched or confirmed.
+#[derive(Event)]
+pub struct MenuChosen(pub MenuItem);
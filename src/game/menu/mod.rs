@@ -0,0 +1,334 @@
+automod::dir!(pub "src/game/menu");
+
+use super::{
+    button::{Back, ButtonSystem},
+    controls::components::Controls,
+    difficulty::components::Preset,
+    display::toggle_fullscreen,
+    leaderboard::components::Leaderboard,
+    replay::components::Playback,
+    save::components::Statistics,
+    FontAssets, ImageAssets,
+};
+use crate::{despawn_recursive, ternary, GameState};
+use bevy::{
+    app::AppExit,
+    ecs::system::SystemParam,
+    prelude::*,
+    window::{PrimaryWindow, WindowMode},
+};
+use components::*;
+use events::MenuChosen;
+
+/// Menu logic.
+///
+/// The title menu the game opens on, built with Bevy UI.
+/// It leads to the store, to the audio and controls screens from its settings page
+/// and to the leaderboard and achievements from its stats page,
+/// which all come back to it once left.
+pub struct MenuPlugin;
+
+impl Plugin for MenuPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<MenuChosen>()
+            .init_resource::<MenuPage>()
+            .init_resource::<MenuCursor>()
+            .init_resource::<SettingsReturn>()
+            .add_systems(
+                OnEnter(GameState::Menu),
+                (skip.run_if(resource_exists::<Playback>), spawn),
+            )
+            .add_systems(
+                Update,
+                (
+                    open.after(ButtonSystem).run_if(in_state(GameState::Ready)),
+                    (
+                        (navigate, click),
+                        choose,
+                        (despawn_recursive::<MenuScreen>, spawn)
+                            .chain()
+                            .run_if(resource_changed::<MenuPage>),
+                        display_items,
+                    )
+                        .chain()
+                        .run_if(in_state(GameState::Menu)),
+                ),
+            )
+            .add_systems(OnExit(GameState::Menu), despawn_recursive::<MenuScreen>);
+    }
+}
+
+const CREDITS: [&str; 5] = [
+    "GAME BY CPEA2506",
+    "BASED ON FLAPPY BIRD",
+    "BY NGUYEN HA DONG",
+    "MADE WITH BEVY",
+    "FONT: TEKO",
+];
+
+/// A replay given at launch plays right away.
+fn skip(mut next_state: ResMut<NextState<GameState>>) {
+    next_state.set(GameState::Ready);
+}
+
+fn open(
+    mut back: Back,
+    mut page: ResMut<MenuPage>,
+    mut cursor: ResMut<MenuCursor>,
+    mut next_state: ResMut<NextState<GameState>>,
+) {
+    if back.pressed() {
+        *page = MenuPage::Main;
+        cursor.0 = 0;
+        next_state.set(GameState::Menu);
+    }
+}
+
+fn spawn(
+    mut commands: Commands,
+    page: Res<MenuPage>,
+    statistics: Res<Statistics>,
+    leaderboard: Res<Leaderboard>,
+    font_assets: Res<FontAssets>,
+    image_assets: Res<ImageAssets>,
+) {
+    let text_style = TextStyle {
+        font: font_assets.teko_bold.clone(),
+        font_size: 24f32,
+        ..default()
+    };
+
+    let lines = match *page {
+        MenuPage::Stats => vec![
+            format!("BEST SCORE: {}", leaderboard.best_overall()),
+            format!("GAMES PLAYED: {}", statistics.games_played),
+            format!("PIPES PASSED: {}", statistics.pipes_passed),
+            format!("FLAPS: {}", statistics.flaps),
+            format!(
+                "MEDALS: {} / {} / {} / {}",
+                statistics.medals.bronze,
+                statistics.medals.silver,
+                statistics.medals.gold,
+                statistics.medals.platinum
+            ),
+        ],
+        MenuPage::Credits => CREDITS.map(String::from).to_vec(),
+        MenuPage::Main | MenuPage::Settings => vec![],
+    };
+
+    commands
+        .spawn((
+            ImageBundle {
+                style: Style {
+                    width: Val::Percent(100f32),
+                    height: Val::Percent(100f32),
+                    flex_direction: FlexDirection::Column,
+                    justify_content: JustifyContent::Center,
+                    align_items: AlignItems::Center,
+                    ..default()
+                },
+                image: UiImage::new(image_assets.bg_day.clone()),
+                ..default()
+            },
+            MenuScreen,
+        ))
+        .with_children(|builder| {
+            builder.spawn(
+                TextBundle::from_section(
+                    page.title(),
+                    TextStyle {
+                        font_size: 56f32,
+                        ..text_style.clone()
+                    },
+                )
+                .with_style(Style {
+                    margin: UiRect::bottom(Val::Px(24f32)),
+                    ..default()
+                }),
+            );
+
+            for line in lines {
+                builder.spawn(TextBundle::from_section(line, text_style.clone()));
+            }
+
+            for item in page.items() {
+                builder
+                    .spawn((
+                        ButtonBundle {
+                            style: Style {
+                                width: Val::Px(180f32),
+                                height: Val::Px(40f32),
+                                margin: UiRect::top(Val::Px(12f32)),
+                                justify_content: JustifyContent::Center,
+                                align_items: AlignItems::Center,
+                                ..default()
+                            },
+                            background_color: Color::rgba(0f32, 0f32, 0f32, 0.5).into(),
+                            ..default()
+                        },
+                        *item,
+                    ))
+                    .with_children(|builder| {
+                        builder.spawn((
+                            TextBundle::from_section("", text_style.clone()),
+                            MenuLabel(*item),
+                        ));
+                    });
+            }
+        });
+}
+
+/// Move through the items with the arrows or a gamepad and pick one on confirm.
+fn navigate(
+    controls: Res<Controls>,
+    page: Res<MenuPage>,
+    mut cursor: ResMut<MenuCursor>,
+    mut menu_chosen: EventWriter<MenuChosen>,
+) {
+    let items = page.items();
+
+    if controls.down {
+        cursor.0 = (cursor.0 + 1) % items.len();
+    }
+
+    if controls.up {
+        cursor.0 = (cursor.0 + items.len() - 1) % items.len();
+    }
+
+    if controls.confirm {
+        menu_chosen.send(MenuChosen(items[cursor.0.min(items.len() - 1)]));
+    } else if controls.back && *page != MenuPage::Main {
+        menu_chosen.send(MenuChosen(MenuItem::Back));
+    }
+}
+
+/// Highlight the item under the mouse and pick the one clicked or touched.
+fn click(
+    page: Res<MenuPage>,
+    mut cursor: ResMut<MenuCursor>,
+    items: Query<(&Interaction, &MenuItem), Changed<Interaction>>,
+    mut menu_chosen: EventWriter<MenuChosen>,
+) {
+    for (interaction, item) in &items {
+        let Some(index) = page.items().iter().position(|other| other == item) else {
+            continue;
+        };
+
+        match interaction {
+            Interaction::Pressed => {
+                menu_chosen.send(MenuChosen(*item));
+            }
+            Interaction::Hovered => cursor.0 = index,
+            Interaction::None => {}
+        }
+    }
+}
+
+/// What the items change outside of the menu.
+#[derive(SystemParam)]
+struct Outcomes<'w, 's> {
+    preset: ResMut<'w, Preset>,
+    settings_return: ResMut<'w, SettingsReturn>,
+    window: Query<'w, 's, &'static mut Window, With<PrimaryWindow>>,
+    next_state: ResMut<'w, NextState<GameState>>,
+    app_exit: EventWriter<'w, AppExit>,
+}
+
+impl Outcomes<'_, '_> {
+    /// Leave the menu for a screen that comes back to it.
+    fn visit(&mut self, state: GameState) {
+        self.settings_return.0 = GameState::Menu;
+        self.next_state.set(state);
+    }
+}
+
+fn choose(
+    mut menu_chosen: EventReader<MenuChosen>,
+    mut page: ResMut<MenuPage>,
+    mut cursor: ResMut<MenuCursor>,
+    mut outcomes: Outcomes,
+) {
+    for MenuChosen(item) in menu_chosen.read() {
+        let next_page = match item {
+            MenuItem::Play => {
+                outcomes.next_state.set(GameState::Ready);
+                None
+            }
+            MenuItem::Store => {
+                outcomes.visit(GameState::Store);
+                None
+            }
+            MenuItem::Leaderboard => {
+                outcomes.visit(GameState::Leaderboard);
+                None
+            }
+            MenuItem::Gallery => {
+                outcomes.visit(GameState::Gallery);
+                None
+            }
+            MenuItem::Settings => Some(MenuPage::Settings),
+            MenuItem::Stats => Some(MenuPage::Stats),
+            MenuItem::Credits => Some(MenuPage::Credits),
+            MenuItem::Back => Some(MenuPage::Main),
+            MenuItem::Quit => {
+                outcomes.app_exit.send(AppExit);
+                None
+            }
+            MenuItem::Audio => {
+                outcomes.visit(GameState::Audio);
+                None
+            }
+            MenuItem::Controls => {
+                outcomes.visit(GameState::Controls);
+                None
+            }
+            MenuItem::Display => {
+                if let Ok(mut window) = outcomes.window.get_single_mut() {
+                    toggle_fullscreen(&mut window);
+                }
+                None
+            }
+            MenuItem::Difficulty => {
+                *outcomes.preset = outcomes.preset.next();
+                None
+            }
+        };
+
+        if let Some(next_page) = next_page {
+            *page = next_page;
+            cursor.0 = 0;
+        }
+    }
+}
+
+/// Name the items after their current setting and light up the highlighted one.
+fn display_items(
+    page: Res<MenuPage>,
+    cursor: Res<MenuCursor>,
+    preset: Res<Preset>,
+    window: Query<&Window, With<PrimaryWindow>>,
+    mut labels: Query<(&MenuLabel, &mut Text)>,
+) {
+    let fullscreen = window
+        .get_single()
+        .is_ok_and(|window| window.mode != WindowMode::Windowed);
+
+    for (MenuLabel(item), mut text) in &mut labels {
+        let label = match item {
+            MenuItem::Display => format!("{}: {}", item.name(), ternary!(fullscreen, "ON", "OFF")),
+            MenuItem::Difficulty => format!("{}: {}", item.name(), preset.name()),
+            _ => item.name().to_string(),
+        };
+        let highlighted = page.items().get(cursor.0) == Some(item);
+        let color = ternary!(highlighted, Color::WHITE, Color::GRAY);
+
+        // Only touch the text when it changes, to not lay it out again every frame.
+        if text.sections[0].value != label {
+            text.sections[0].value = label;
+        }
+
+        if text.sections[0].style.color != color {
+            text.sections[0].style.color = color;
+        }
+    }
+}
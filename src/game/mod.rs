@@ -1,77 +1,237 @@
 automod::dir!("src/game");
 
+pub mod achievement;
 pub mod audio;
+pub mod autopilot;
 mod background;
 mod base;
-mod bird;
-mod collision;
+pub mod bird;
+pub mod button;
+mod coin;
+pub mod collision;
+pub mod config;
+pub mod controls;
+pub mod difficulty;
+pub mod display;
 pub mod game_over;
+pub mod gym;
+pub mod headless;
+pub mod interpolation;
+pub mod leaderboard;
+pub mod menu;
+pub mod online;
+mod pause;
 pub mod pipe;
 mod ready_message;
-mod score;
+pub mod replay;
+pub mod save;
+pub mod score;
+mod store;
 
 use crate::GameState;
-use audio::{components::AmbientMusic, events::AudioEvent, AudioPlugin};
+use achievement::{AchievementDataPlugin, AchievementPlugin};
+use audio::{
+    components::AmbientMusic,
+    events::{AudioControl, AudioEvent},
+    AudioPlugin,
+};
+use autopilot::AutopilotPlugin;
 use background::BackgroundPlugin;
 use base::BasePlugin;
 use bevy::prelude::*;
 use bevy_asset_loader::prelude::*;
 use bird::BirdPlugin;
+use button::{components::SpriteButton, events::ButtonClicked, ButtonPlugin, ButtonSystem};
+use coin::CoinPlugin;
 use collision::CollisionPlugin;
-use game_over::GameOverPlugin;
+use config::{components::GameConfig, ConfigPlugin};
+use controls::{components::Controls, ControlsPlugin};
+use difficulty::DifficultyPlugin;
+use display::DisplayPlugin;
+use game_over::{
+    components::{MenuButton, RestartButton},
+    events::RestartButtonDisplayed,
+    GameOverPlugin,
+};
+use interpolation::InterpolationPlugin;
+use leaderboard::LeaderboardPlugin;
+use menu::MenuPlugin;
+use online::OnlinePlugin;
+pub use options::LaunchOptions;
+use pause::PausePlugin;
 use pipe::PipePlugin;
+use rand::{random, Rng};
 use ready_message::ReadyMessagePlugin;
+use replay::{components::Playback, ReplayPlugin};
 pub use resources::*;
+use save::SavePlugin;
 use score::ScorePlugin;
+use store::StorePlugin;
 
+/// Steps of the simulation, run in this order on each fixed timestep.
+#[derive(SystemSet, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Simulation {
+    Input,
+    Movement,
+    Collision,
+    Scoring,
+}
+
+/// The whole game: its assets, sounds and saves on top of the gameplay.
 pub struct GamePlugin;
 
+impl GamePlugin {
+    /// Number of simulation steps per second.
+    pub const TICK_RATE: f64 = 60f64;
+}
+
 impl Plugin for GamePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_loading_state(
+            LoadingState::new(GameState::AssetLoading)
+                .continue_to_state(GameState::Menu)
+                .with_dynamic_assets_file::<StandardDynamicAssetCollection>("audios.assets.ron")
+                .with_dynamic_assets_file::<StandardDynamicAssetCollection>("images.assets.ron")
+                .with_dynamic_assets_file::<StandardDynamicAssetCollection>("fonts.assets.ron")
+                .load_collection::<ImageAssets>()
+                .load_collection::<FontAssets>()
+                .load_collection::<AudioAssets>(),
+        )
+        .add_plugins((
+            GameplayPlugin,
+            AudioPlugin,
+            SavePlugin,
+            ConfigPlugin,
+            AchievementDataPlugin,
+            OnlinePlugin,
+            DisplayPlugin,
+        ));
+    }
+}
+
+/// Rules and scenes of the game.
+///
+/// Nothing here needs a window, a renderer or an audio device,
+/// as long as the asset resources are provided.
+pub struct GameplayPlugin;
+
+impl Plugin for GameplayPlugin {
     fn build(&self, app: &mut App) {
         app.init_resource::<DateTime>()
-            .add_loading_state(
-                LoadingState::new(GameState::AssetLoading)
-                    .continue_to_state(GameState::Ready)
-                    .with_dynamic_assets_file::<StandardDynamicAssetCollection>("audios.assets.ron")
-                    .with_dynamic_assets_file::<StandardDynamicAssetCollection>("images.assets.ron")
-                    .with_dynamic_assets_file::<StandardDynamicAssetCollection>("fonts.assets.ron")
-                    .load_collection::<ImageAssets>()
-                    .load_collection::<FontAssets>()
-                    .load_collection::<AudioAssets>(),
+            .init_resource::<LaunchOptions>()
+            .init_resource::<GameConfig>()
+            .add_event::<AudioEvent>()
+            .add_event::<AudioControl>()
+            .insert_resource(Time::<Fixed>::from_hz(GamePlugin::TICK_RATE))
+            .configure_sets(
+                FixedUpdate,
+                (
+                    Simulation::Input,
+                    Simulation::Movement,
+                    Simulation::Collision,
+                    Simulation::Scoring,
+                )
+                    .chain(),
             )
             .add_plugins((
-                AudioPlugin,
                 ReadyMessagePlugin,
                 BackgroundPlugin,
                 BasePlugin,
                 BirdPlugin,
                 PipePlugin,
+                CoinPlugin,
                 CollisionPlugin,
                 ScorePlugin,
                 GameOverPlugin,
+                PausePlugin,
+                StorePlugin,
+                InterpolationPlugin,
+            ))
+            .add_plugins((
+                ControlsPlugin,
+                ButtonPlugin,
+                ReplayPlugin,
+                AutopilotPlugin,
+                DifficultyPlugin,
+                AchievementPlugin,
+                LeaderboardPlugin,
+                MenuPlugin,
             ))
-            .add_systems(OnEnter(GameState::Playing), play_ambient_music)
-            .add_systems(OnExit(GameState::Playing), stop_ambient_music)
-            .add_systems(OnExit(GameState::Over), (init_datetime, stop_all_songs));
+            .add_systems(
+                Update,
+                (
+                    start_game.after(ButtonSystem).run_if(
+                        in_state(GameState::Ready).and_then(not(resource_exists::<Playback>)),
+                    ),
+                    restart_game
+                        .after(ButtonSystem)
+                        .run_if(in_state(GameState::Over)),
+                ),
+            )
+            .add_systems(OnExit(GameState::AssetLoading), new_round)
+            .add_systems(OnExit(GameState::Over), (new_round, stop_sound_effects))
+            .add_systems(
+                OnTransition {
+                    from: GameState::Paused,
+                    to: GameState::Ready,
+                },
+                (new_round, stop_sound_effects),
+            );
     }
 }
 
-fn init_datetime(mut commands: Commands) {
-    commands.insert_resource(DateTime::default())
+/// Seed a new run and pick its time of day.
+fn new_round(mut commands: Commands, options: Res<LaunchOptions>) {
+    let mut rng = GameRng::new(options.seed.unwrap_or_else(random));
+
+    info!("Starting run with seed {}", rng.seed());
+    commands.insert_resource(rng.gen::<DateTime>());
+    commands.insert_resource(rng);
 }
 
-fn stop_all_songs(audio_sinks: Query<&AudioSink>) {
-    for sink in &audio_sinks {
-        sink.stop();
+/// Flap into a run, unless the click or touch went down on a button.
+fn start_game(
+    controls: Res<Controls>,
+    buttons: Query<&Interaction, With<SpriteButton>>,
+    mut next_state: ResMut<NextState<GameState>>,
+) {
+    let on_button = buttons
+        .iter()
+        .any(|interaction| *interaction == Interaction::Pressed);
+
+    if controls.flap && !on_button {
+        next_state.set(GameState::Playing);
     }
 }
 
-fn play_ambient_music(mut audio_event: EventWriter<AudioEvent>, audio_assets: Res<AudioAssets>) {
-    audio_event.send(AudioEvent::new_with_volume(&audio_assets.theme, true, 0.2));
+fn restart_game(
+    controls: Res<Controls>,
+    mut next_state: ResMut<NextState<GameState>>,
+    restart_btn_displayed: EventReader<RestartButtonDisplayed>,
+    mut button_clicked: EventReader<ButtonClicked>,
+    restart_btn: Query<(), With<RestartButton>>,
+    menu_btn: Query<(), With<MenuButton>>,
+) {
+    if restart_btn_displayed.is_empty() {
+        return;
+    }
+
+    // A button focused with the arrows goes before the restart binding.
+    match button_clicked.read().last() {
+        Some(ButtonClicked(button)) if restart_btn.contains(*button) => {
+            next_state.set(GameState::Ready)
+        }
+        Some(ButtonClicked(button)) if menu_btn.contains(*button) => {
+            next_state.set(GameState::Menu)
+        }
+        None if controls.restart => next_state.set(GameState::Ready),
+        _ => {}
+    }
 }
 
-fn stop_ambient_music(ambient_sinks: Query<&AudioSink, With<AmbientMusic>>) {
-    for sink in &ambient_sinks {
+/// Cut the sounds of the last run, the music fades on its own.
+fn stop_sound_effects(audio_sinks: Query<&AudioSink, Without<AmbientMusic>>) {
+    for sink in &audio_sinks {
         sink.stop();
     }
 }
@@ -0,0 +1,97 @@
+use crate::game::{
+    leaderboard::components::{Entry, Leaderboard},
+    replay::components::Replay,
+};
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::sync::{Arc, Mutex};
+
+/// A finished run sent to the score server,
+/// with everything needed to play it again.
+#[derive(Clone, Serialize, Deserialize)]
+pub struct Submission {
+    pub entry: Entry,
+    pub replay: Replay,
+    /// Digest of the game config the run was played with.
+    #[serde(default)]
+    pub config: u64,
+}
+
+/// Score server the runs are submitted to.
+#[derive(Resource, Clone)]
+pub struct ScoreServer {
+    pub url: String,
+}
+
+impl ScoreServer {
+    pub fn new(url: &str) -> Self {
+        ScoreServer {
+            url: url.trim_end_matches('/').to_string(),
+        }
+    }
+
+    pub fn runs(&self) -> String {
+        format!("{}/runs", self.url)
+    }
+
+    pub fn leaderboard(&self) -> String {
+        format!("{}/leaderboard", self.url)
+    }
+}
+
+/// Runs waiting to be accepted by the server, kept across launches.
+#[derive(Resource, Default, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Outbox(pub Vec<Submission>);
+
+/// Requests to the score server.
+#[derive(Resource, Default)]
+pub(super) struct Connection {
+    /// Whether a submission is waiting for its reply.
+    pub sending: bool,
+    pub retry: Timer,
+    /// Seconds to wait after the next failure.
+    pub delay: f32,
+}
+
+impl Connection {
+    /// Seconds before retrying a first failure, doubled on each following one.
+    pub const MIN_DELAY: f32 = 5f32;
+    pub const MAX_DELAY: f32 = 300f32;
+
+    pub fn succeeded(&mut self) {
+        self.sending = false;
+        self.delay = Self::MIN_DELAY;
+    }
+
+    pub fn failed(&mut self) {
+        self.sending = false;
+        self.retry = Timer::from_seconds(self.delay.max(Self::MIN_DELAY), TimerMode::Once);
+        self.delay = (self.delay * 2f32).clamp(Self::MIN_DELAY, Self::MAX_DELAY);
+    }
+}
+
+pub(super) enum Reply {
+    /// Status code answered to a submission.
+    Submitted(Result<u16, String>),
+    Leaderboard(Result<Leaderboard, String>),
+}
+
+/// Replies received on other threads, handled on the next frame.
+#[derive(Resource, Default, Clone)]
+pub(super) struct Inbox(Arc<Mutex<Vec<Reply>>>);
+
+impl Inbox {
+    pub fn push(&self, reply: Reply) {
+        if let Ok(mut replies) = self.0.lock() {
+            replies.push(reply);
+        }
+    }
+
+    pub fn take(&self) -> Vec<Reply> {
+        self.0
+            .lock()
+            .map(|mut replies| std::mem::take(&mut *replies))
+            .unwrap_or_default()
+    }
+}
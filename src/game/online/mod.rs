@@ -0,0 +1,186 @@
+automod::dir!(pub "src/game/online");
+
+use super::{
+    config::components::GameConfig,
+    difficulty::components::Preset,
+    leaderboard::{
+        components::{GlobalLeaderboard, Leaderboard},
+        events::EntryAdded,
+    },
+    replay::components::{Recording, Replay, RunTicks},
+    storage, GameRng, LaunchOptions,
+};
+use crate::GameState;
+use bevy::prelude::*;
+use components::*;
+
+const OUTBOX_FILE: &str = "outbox.ron";
+
+/// Online logic.
+///
+/// Only runs when a score server is given with `--server`.
+pub struct OnlinePlugin;
+
+impl Plugin for OnlinePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<Outbox>()
+            .init_resource::<Connection>()
+            .init_resource::<Inbox>()
+            .add_systems(OnEnter(GameState::AssetLoading), connect)
+            .add_systems(
+                Update,
+                (queue.run_if(on_event::<EntryAdded>()), send, receive)
+                    .chain()
+                    .run_if(resource_exists::<ScoreServer>),
+            )
+            .add_systems(
+                OnEnter(GameState::Leaderboard),
+                fetch_leaderboard.run_if(resource_exists::<ScoreServer>),
+            );
+    }
+}
+
+/// Pick the server and the runs that could not be sent last time.
+fn connect(mut commands: Commands, options: Res<LaunchOptions>) {
+    let Some(url) = &options.server else {
+        return;
+    };
+
+    commands.insert_resource(ScoreServer::new(url));
+
+    let Ok(contents) = storage::read(OUTBOX_FILE) else {
+        return;
+    };
+
+    match ron::from_str::<Outbox>(&contents) {
+        Ok(outbox) => commands.insert_resource(outbox),
+        Err(err) => warn!("Ignoring corrupted outbox file: {err}"),
+    }
+}
+
+fn queue(
+    mut entry_added: EventReader<EntryAdded>,
+    rng: Res<GameRng>,
+    ticks: Res<RunTicks>,
+    recording: Res<Recording>,
+    config: Res<GameConfig>,
+    mut outbox: ResMut<Outbox>,
+) {
+    for EntryAdded(entry) in entry_added.read() {
+        // The server only knows the difficulties shipped with the game.
+        if entry.preset == Preset::Custom {
+            continue;
+        }
+
+        outbox.0.push(Submission {
+            entry: entry.clone(),
+            replay: Replay::record(rng.seed(), entry.preset, &ticks, &recording),
+            config: config.digest(),
+        });
+    }
+
+    save(&outbox);
+}
+
+/// Submit the oldest waiting run, one at a time.
+fn send(
+    time: Res<Time>,
+    server: Res<ScoreServer>,
+    outbox: Res<Outbox>,
+    inbox: Res<Inbox>,
+    mut connection: ResMut<Connection>,
+) {
+    connection.retry.tick(time.delta());
+
+    if connection.sending || !connection.retry.finished() {
+        return;
+    }
+
+    let Some(submission) = outbox.0.first() else {
+        return;
+    };
+
+    let body = match ron::to_string(submission) {
+        Ok(body) => body,
+        Err(err) => {
+            warn!("Failed to write run submission: {err}");
+            return;
+        }
+    };
+    let inbox = inbox.clone();
+
+    connection.sending = true;
+
+    ehttp::fetch(
+        ehttp::Request::post(server.runs(), body.into_bytes()),
+        move |result| inbox.push(Reply::Submitted(result.map(|response| response.status))),
+    );
+}
+
+fn receive(
+    mut commands: Commands,
+    inbox: Res<Inbox>,
+    mut outbox: ResMut<Outbox>,
+    mut connection: ResMut<Connection>,
+) {
+    for reply in inbox.take() {
+        match reply {
+            // Runs the server refuses would be refused again.
+            Reply::Submitted(Ok(status @ (200..=299 | 400 | 422))) => {
+                if status >= 400 {
+                    warn!("Score server rejected a run with status {status}");
+                }
+
+                if !outbox.0.is_empty() {
+                    outbox.0.remove(0);
+                }
+
+                connection.succeeded();
+                save(&outbox);
+            }
+            Reply::Submitted(Ok(status)) => {
+                warn!("Score server failed with status {status}, retrying later");
+                connection.failed();
+            }
+            Reply::Submitted(Err(err)) => {
+                warn!("Failed to reach score server, retrying later: {err}");
+                connection.failed();
+            }
+            Reply::Leaderboard(Ok(leaderboard)) => {
+                commands.insert_resource(GlobalLeaderboard(leaderboard));
+            }
+            Reply::Leaderboard(Err(err)) => warn!("Failed to fetch global leaderboard: {err}"),
+        }
+    }
+}
+
+fn fetch_leaderboard(server: Res<ScoreServer>, inbox: Res<Inbox>) {
+    let inbox = inbox.clone();
+
+    ehttp::fetch(ehttp::Request::get(server.leaderboard()), move |result| {
+        let leaderboard = result.and_then(|response| {
+            if !response.ok {
+                return Err(format!("status {}", response.status));
+            }
+
+            ron::from_str(response.text().unwrap_or_default())
+                .map(|entries| Leaderboard {
+                    entries,
+                    ..default()
+                })
+                .map_err(|err| err.to_string())
+        });
+
+        inbox.push(Reply::Leaderboard(leaderboard));
+    });
+}
+
+fn save(outbox: &Outbox) {
+    let result = ron::to_string(outbox)
+        .map_err(|err| err.to_string())
+        .and_then(|contents| storage::write(OUTBOX_FILE, &contents).map_err(|err| err.to_string()));
+
+    if let Err(err) = result {
+        warn!("Failed to write outbox file: {err}");
+    }
+}
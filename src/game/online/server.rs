@@ -0,0 +1,126 @@
+//! Rules of the reference score server, see `src/bin/flappybust-server.rs`.
+
+use super::components::Submission;
+use crate::game::{
+    config::components::GameConfig,
+    difficulty::components::Preset,
+    game_over::components::MedalType,
+    headless,
+    leaderboard::components::{Entry, Leaderboard, NameEntry},
+};
+use std::sync::{Mutex, PoisonError};
+
+/// Longest run ranked, an hour of play including the ready screen.
+pub const MAX_TICKS: u64 = 216_000;
+/// Steps played after the last flap, enough for the bird to fall from the sky.
+const GRACE_TICKS: u64 = 600;
+
+/// Play a run again and return its entry if it really reached its score.
+///
+/// The time of day and the bird shown on the table are the ones the seed picks,
+/// not the ones claimed by the submission.
+pub fn verify(submission: Submission) -> Result<Entry, String> {
+    let Submission {
+        mut entry,
+        replay,
+        config,
+    } = submission;
+    let server_config = GameConfig::default();
+
+    if config != server_config.digest() {
+        return Err("game config does not match the server".to_string());
+    }
+
+    if entry.preset != replay.preset {
+        return Err("difficulty does not match the replay".to_string());
+    }
+
+    if entry.preset == Preset::Custom {
+        return Err("custom difficulty runs are not ranked".to_string());
+    }
+
+    if !replay.flaps.is_sorted() {
+        return Err("flaps are out of order".to_string());
+    }
+
+    let last_flap = replay.flaps.last().copied().unwrap_or_default();
+
+    if replay.start.saturating_add(last_flap) > MAX_TICKS {
+        return Err("run is too long".to_string());
+    }
+
+    let report = headless::simulate(replay, last_flap + GRACE_TICKS);
+
+    let Some(bird) = report.bird else {
+        return Err("run never starts".to_string());
+    };
+
+    if report.death.is_none() {
+        return Err("run never ends".to_string());
+    }
+
+    if report.score != entry.score {
+        return Err(format!(
+            "run scores {} instead of {}",
+            report.score, entry.score
+        ));
+    }
+
+    let mut name = NameEntry::default();
+    name.push_str(&entry.name);
+
+    entry.name = name.name();
+    entry.bird = bird;
+    entry.datetime = report.datetime;
+    entry.medal = MedalType::from_score(entry.score, &server_config.medals);
+
+    Ok(entry)
+}
+
+/// Answer a request with its status code and body.
+///
+/// - `GET /leaderboard` lists the best runs of each difficulty.
+/// - `POST /runs` verifies a submission and ranks it,
+///   answering `201` with its rank, `200` when it is not good enough,
+///   `400` when it cannot be read and `422` when it is not legit.
+///
+/// The leaderboard is only locked around reading and ranking,
+/// so other requests are answered while a run is played again.
+pub fn respond(
+    method: &str,
+    path: &str,
+    body: &str,
+    leaderboard: &Mutex<Leaderboard>,
+) -> (u16, String) {
+    let lock = || leaderboard.lock().unwrap_or_else(PoisonError::into_inner);
+
+    match (method, path) {
+        ("GET", "/leaderboard") => match ron::to_string(&lock().entries) {
+            Ok(entries) => (200, entries),
+            Err(err) => (500, err.to_string()),
+        },
+        ("POST", "/runs") => {
+            let submission = match ron::from_str::<Submission>(body) {
+                Ok(submission) => submission,
+                Err(err) => return (400, err.to_string()),
+            };
+
+            let entry = match verify(submission) {
+                Ok(entry) => entry,
+                Err(err) => return (422, err),
+            };
+            let mut leaderboard = lock();
+
+            if !leaderboard.qualifies(entry.preset, entry.score) {
+                return (200, "not ranked".to_string());
+            }
+
+            let rank = leaderboard.rank(entry.preset, entry.score);
+
+            leaderboard.insert(entry);
+
+            (201, (rank + 1).to_string())
+        }
+        _ => (404, "not found".to_string()),
+    }
+}
@@ -0,0 +1,103 @@
+use super::difficulty::components::Preset;
+use bevy::prelude::*;
+
+/// Options given when launching the game.
+///
+/// They are read from command line arguments (`--seed 42`),
+/// environment variables (`FLAPPYBUST_SEED=42`)
+/// or the page URL on the web build (`?seed=42`).
+#[derive(Resource, Default, Clone)]
+pub struct LaunchOptions {
+    /// Seed used by every run instead of a random one.
+    pub seed: Option<u64>,
+    /// Replay file to play instead of reading the player input.
+    pub replay: Option<String>,
+    /// Play without a window and print how the run ended.
+    pub headless: bool,
+    /// Let the bot fly every run.
+    pub autopilot: bool,
+    /// Difficulty used instead of the one picked on the ready screen.
+    pub difficulty: Option<Preset>,
+    /// Score server to submit runs to and read the global leaderboard from.
+    pub server: Option<String>,
+}
+
+impl LaunchOptions {
+    pub fn from_env() -> Self {
+        let params = params();
+
+        Self::from_lookup(|name| {
+            params
+                .iter()
+                .find(|(key, _)| key == name)
+                .map(|(_, value)| value.clone())
+                .or_else(|| std::env::var(format!("FLAPPYBUST_{}", name.to_uppercase())).ok())
+        })
+    }
+
+    /// Options out of the value `find` gives for each name, if any.
+    ///
+    /// A flag given without a value is on, `0`, `false`, `no` and `off` turn it off.
+    pub fn from_lookup(find: impl Fn(&str) -> Option<String>) -> Self {
+        let flag = |name: &str| {
+            find(name).is_some_and(|value| {
+                !matches!(
+                    value.trim().to_lowercase().as_str(),
+                    "0" | "false" | "no" | "off"
+                )
+            })
+        };
+
+        LaunchOptions {
+            seed: find("seed").and_then(|seed| seed.parse().ok()),
+            replay: find("replay").filter(|path| !path.is_empty()),
+            headless: flag("headless"),
+            autopilot: flag("autopilot"),
+            difficulty: find("difficulty").and_then(|name| Preset::from_name(&name)),
+            server: find("server").filter(|url| !url.is_empty()),
+        }
+    }
+}
+
+/// Key value pairs of `--key value` or `--key=value` command line arguments.
+#[cfg(not(target_arch = "wasm32"))]
+fn params() -> Vec<(String, String)> {
+    let mut params = Vec::new();
+    let mut args = std::env::args().skip(1).peekable();
+
+    while let Some(arg) = args.next() {
+        let Some(key) = arg.strip_prefix("--") else {
+            continue;
+        };
+
+        match key.split_once('=') {
+            Some((key, value)) => params.push((key.to_string(), value.to_string())),
+            None => {
+                let value = args.next_if(|value| !value.starts_with("--"));
+
+                params.push((key.to_string(), value.unwrap_or_default()));
+            }
+        }
+    }
+
+    params
+}
+
+/// Key value pairs of the page URL query.
+#[cfg(target_arch = "wasm32")]
+fn params() -> Vec<(String, String)> {
+    let search = web_sys::window()
+        .and_then(|window| window.location().search().ok())
+        .unwrap_or_default();
+
+    search
+        .trim_start_matches('?')
+        .split('&')
+        .filter(|pair| !pair.is_empty())
+        .map(|pair| {
+            let (key, value) = pair.split_once('=').unwrap_or((pair, ""));
+
+            (key.to_string(), value.to_string())
+        })
+        .collect()
+}
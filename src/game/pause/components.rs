@@ -0,0 +1,43 @@
+use bevy::prelude::*;
+
+#[derive(Component)]
+pub struct PauseOverlay;
+
+/// Options that can be chosen from the pause overlay.
+#[derive(Component, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PauseOption {
+    #[default]
+    Resume,
+    Restart,
+    Quit,
+}
+
+impl PauseOption {
+    pub const ALL: [PauseOption; 3] = [Self::Resume, Self::Restart, Self::Quit];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            PauseOption::Resume => "RESUME",
+            PauseOption::Restart => "RESTART",
+            PauseOption::Quit => "QUIT",
+        }
+    }
+}
+
+/// Currently highlighted option of the pause overlay.
+#[derive(Resource, Default)]
+pub struct PauseSelection(pub usize);
+
+impl PauseSelection {
+    pub fn get(&self) -> PauseOption {
+        PauseOption::ALL[self.0]
+    }
+
+    pub fn next(&mut self) {
+        self.0 = (self.0 + 1) % PauseOption::ALL.len();
+    }
+
+    pub fn prev(&mut self) {
+        self.0 = (self.0 + PauseOption::ALL.len() - 1) % PauseOption::ALL.len();
+    }
+}
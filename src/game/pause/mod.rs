@@ -0,0 +1,208 @@
+automod::dir!(pub "src/game/pause");
+
+use super::{
+    audio::components::AmbientMusic, controls::components::Controls, display::to_world, FontAssets,
+};
+use crate::{despawn_recursive, ternary, BasicMath, GameState, SCREEN_HEIGHT, SCREEN_WIDTH};
+use bevy::{app::AppExit, prelude::*, window::WindowFocused};
+use components::*;
+
+/// Pause logic.
+pub struct PausePlugin;
+
+impl Plugin for PausePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<PauseSelection>()
+            .add_systems(
+                Update,
+                (
+                    pause.run_if(in_state(GameState::Playing)),
+                    (navigate_options, click_option, highlight_option)
+                        .run_if(in_state(GameState::Paused)),
+                ),
+            )
+            .add_systems(
+                OnEnter(GameState::Paused),
+                (spawn_overlay, pause_ambient_music),
+            )
+            .add_systems(
+                OnExit(GameState::Paused),
+                (despawn_recursive::<PauseOverlay>, resume_ambient_music),
+            );
+    }
+}
+
+const OPTION_SIZE: Vec2 = Vec2::new(140f32, 36f32);
+
+fn spawn_overlay(
+    mut commands: Commands,
+    font_assets: Res<FontAssets>,
+    mut selection: ResMut<PauseSelection>,
+) {
+    *selection = PauseSelection::default();
+
+    let text_style = TextStyle {
+        font: font_assets.teko_bold.clone(),
+        font_size: 36f32,
+        ..default()
+    };
+
+    commands
+        .spawn((
+            SpriteBundle {
+                transform: Transform::from_xyz(0f32, 0f32, 0.9),
+                sprite: Sprite {
+                    color: Color::rgba(0f32, 0f32, 0f32, 0.6),
+                    custom_size: Some(Vec2::new(SCREEN_WIDTH, SCREEN_HEIGHT)),
+                    ..default()
+                },
+                ..default()
+            },
+            PauseOverlay,
+        ))
+        .with_children(|builder| {
+            builder.spawn(Text2dBundle {
+                text: Text::from_section(
+                    "PAUSED",
+                    TextStyle {
+                        font_size: 64f32,
+                        ..text_style.clone()
+                    },
+                )
+                .with_justify(JustifyText::Center),
+                transform: Transform::from_xyz(0f32, 110f32, 0.1),
+                ..default()
+            });
+
+            for (i, option) in PauseOption::ALL.into_iter().enumerate() {
+                builder
+                    .spawn((
+                        SpriteBundle {
+                            transform: Transform::from_xyz(0f32, 30f32 - 50f32 * i as f32, 0.1),
+                            sprite: Sprite {
+                                color: Color::NONE,
+                                custom_size: Some(OPTION_SIZE),
+                                ..default()
+                            },
+                            ..default()
+                        },
+                        option,
+                    ))
+                    .with_children(|builder| {
+                        builder.spawn(Text2dBundle {
+                            text: Text::from_section(option.label(), text_style.clone())
+                                .with_justify(JustifyText::Center),
+                            transform: Transform::from_xyz(0f32, 0f32, 0.1),
+                            ..default()
+                        });
+                    });
+            }
+        });
+}
+
+fn pause(
+    controls: Res<Controls>,
+    mut window_focused: EventReader<WindowFocused>,
+    mut next_state: ResMut<NextState<GameState>>,
+) {
+    let lost_focus = window_focused.read().any(|event| !event.focused);
+
+    if lost_focus || controls.pause {
+        next_state.set(GameState::Paused);
+    }
+}
+
+fn navigate_options(
+    controls: Res<Controls>,
+    mut selection: ResMut<PauseSelection>,
+    next_state: ResMut<NextState<GameState>>,
+    app_exit: EventWriter<AppExit>,
+) {
+    if controls.down {
+        selection.next();
+    }
+
+    if controls.up {
+        selection.prev();
+    }
+
+    if controls.pause {
+        choose(PauseOption::Resume, next_state, app_exit);
+    } else if controls.confirm {
+        choose(selection.get(), next_state, app_exit);
+    }
+}
+
+fn click_option(
+    controls: Res<Controls>,
+    camera: Query<(&Camera, &GlobalTransform)>,
+    options: Query<(&GlobalTransform, &PauseOption)>,
+    next_state: ResMut<NextState<GameState>>,
+    app_exit: EventWriter<AppExit>,
+) {
+    let Some(pointer) = controls.pointer else {
+        return;
+    };
+
+    let Some(cursor) = to_world(pointer, camera.get_single().ok()) else {
+        return;
+    };
+
+    let clicked = options.iter().find(|(transform, _)| {
+        let distance = (cursor - transform.translation().truncate()).abs();
+
+        distance.cmplt(OPTION_SIZE.half()).all()
+    });
+
+    if let Some((_, option)) = clicked {
+        choose(*option, next_state, app_exit);
+    }
+}
+
+fn choose(
+    option: PauseOption,
+    mut next_state: ResMut<NextState<GameState>>,
+    mut app_exit: EventWriter<AppExit>,
+) {
+    match option {
+        PauseOption::Resume => next_state.set(GameState::Playing),
+        PauseOption::Restart => next_state.set(GameState::Ready),
+        PauseOption::Quit => {
+            app_exit.send(AppExit);
+        }
+    }
+}
+
+fn highlight_option(
+    selection: Res<PauseSelection>,
+    options: Query<(&PauseOption, &Children)>,
+    mut texts: Query<&mut Text>,
+) {
+    if !selection.is_changed() {
+        return;
+    }
+
+    for (option, children) in &options {
+        let color = ternary!(*option == selection.get(), Color::WHITE, Color::GRAY);
+
+        for &child in children {
+            if let Ok(mut text) = texts.get_mut(child) {
+                for section in &mut text.sections {
+                    section.style.color = color;
+                }
+            }
+        }
+    }
+}
+
+fn pause_ambient_music(ambient_sinks: Query<&AudioSink, With<AmbientMusic>>) {
+    for sink in &ambient_sinks {
+        sink.pause();
+    }
+}
+
+fn resume_ambient_music(ambient_sinks: Query<&AudioSink, With<AmbientMusic>>) {
+    for sink in &ambient_sinks {
+        sink.play();
+    }
+}
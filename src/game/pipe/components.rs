@@ -1,10 +1,57 @@
 use crate::{
-    game::{DateTime, ImageAssets},
-    SCREEN_WIDTH,
+    game::{
+        coin::components::Coin, difficulty::components::Difficulty,
+        interpolation::components::Interpolated, DateTime, GameRng, ImageAssets,
+    },
+    BasicMath,
 };
 use bevy::prelude::*;
-use flappybust::{ternary, BasicMath};
-use rand::{distributions::Uniform, prelude::Distribution, thread_rng};
+use itertools::Itertools;
+use rand::{distributions::Uniform, prelude::Distribution, Rng};
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Serialize, Deserialize)]
+pub enum PipeColor {
+    Green,
+    Red,
+    Blue,
+}
+
+impl PipeColor {
+    pub fn texture(self, image_assets: &ImageAssets) -> Handle<Image> {
+        match self {
+            PipeColor::Green => image_assets.green_pipe.clone(),
+            PipeColor::Red => image_assets.red_pipe.clone(),
+            PipeColor::Blue => image_assets.blue_pipe.clone(),
+        }
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            PipeColor::Green => "GREEN",
+            PipeColor::Red => "RED",
+            PipeColor::Blue => "BLUE",
+        }
+    }
+}
+
+impl From<DateTime> for PipeColor {
+    fn from(datetime: DateTime) -> Self {
+        match datetime {
+            DateTime::Day => PipeColor::Green,
+            DateTime::Night => PipeColor::Red,
+        }
+    }
+}
+
+/// Opening between a pair of pipes.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Gap {
+    /// Right edge of the pipes.
+    pub right: f32,
+    pub top: f32,
+    pub bottom: f32,
+}
 
 #[derive(Component, Default)]
 pub struct Pipe {
@@ -18,7 +65,6 @@ pub struct Pipe {
 impl Pipe {
     pub(super) const WIDTH: f32 = 52f32;
     pub(super) const HEIGHT: f32 = 320f32;
-    const GAP: f32 = 80f32;
 
     fn new(x: f32, y: f32, flip_y: bool) -> Self {
         Pipe {
@@ -29,7 +75,7 @@ impl Pipe {
         }
     }
 
-    fn generate_bundle(self, texture: &Handle<Image>) -> (SpriteBundle, Self) {
+    fn generate_bundle(self, texture: &Handle<Image>) -> (SpriteBundle, Interpolated, Self) {
         (
             SpriteBundle {
                 sprite: Sprite {
@@ -40,42 +86,72 @@ impl Pipe {
                 transform: Transform::from_translation(self.translation),
                 ..default()
             },
+            Interpolated::new(self.translation),
             self,
         )
     }
 
-    /// Generate number of pipes by `num_pipe`.
+    /// First gap not yet fully passed by something whose left edge is at `x`.
+    pub fn next_gap<'a>(
+        pipes: impl Iterator<Item = (&'a Pipe, &'a Interpolated)>,
+        x: f32,
+    ) -> Option<Gap> {
+        pipes
+            .tuples()
+            .map(|((pipe, position), (_, other_position))| {
+                let lower_y = position.current.y.min(other_position.current.y);
+                let upper_y = position.current.y.max(other_position.current.y);
+
+                Gap {
+                    right: position.current.x + pipe.size.x.half(),
+                    top: upper_y - pipe.size.y.half(),
+                    bottom: lower_y + pipe.size.y.half(),
+                }
+            })
+            .filter(|gap| gap.right > x)
+            .min_by(|a, b| a.right.total_cmp(&b.right))
+    }
+
+    /// Generate number of pipes by `num_pipe`, the first one at `x`.
     #[inline]
     pub(super) fn genrate_self(
         num_pipe: u32,
-        first_time: bool,
+        x: f32,
+        difficulty: &Difficulty,
         commands: &mut Commands,
         image_assets: &Res<ImageAssets>,
-        datetime: &Res<DateTime>,
+        color: PipeColor,
+        rng: &mut GameRng,
     ) {
-        let texture = match **datetime {
-            DateTime::Day => image_assets.green_pipe.clone(),
-            DateTime::Night => image_assets.red_pipe.clone(),
-        };
-
-        let mut rng = thread_rng();
-        let y_between = Uniform::new(-240f32, -50f32);
+        let texture = color.texture(image_assets);
+        let y_between = Uniform::new_inclusive(difficulty.lowest, difficulty.highest);
 
-        // Spawn first 2 pipes.
         (0..num_pipe).for_each(|i| {
             let pipe = Self::new(
-                ternary!(first_time, SCREEN_WIDTH, SCREEN_WIDTH.half())
-                    + Self::WIDTH.half()
-                    + 175f32 * i as f32,
-                y_between.sample(&mut rng),
+                x + difficulty.spacing * i as f32,
+                y_between.sample(rng),
                 false,
             );
             let flipped_pipe = Self::new(
                 pipe.translation.x,
-                pipe.translation.y + Self::GAP + Self::HEIGHT,
+                pipe.translation.y + difficulty.gap + Self::HEIGHT,
                 true,
             );
 
+            // Randomly place a coin somewhere inside the gap, when it fits there.
+            if difficulty.gap > Coin::SIZE && rng.gen_bool(Coin::SPAWN_CHANCE) {
+                let gap_bottom = pipe.translation.y + Self::HEIGHT.half();
+                let coin_y = rng.gen_range(
+                    gap_bottom + Coin::SIZE.half()..gap_bottom + difficulty.gap - Coin::SIZE.half(),
+                );
+
+                commands.spawn(Coin::generate_bundle(
+                    pipe.translation.x,
+                    coin_y,
+                    &image_assets.coin,
+                ));
+            }
+
             commands.spawn_batch(vec![
                 pipe.generate_bundle(&texture),
                 flipped_pipe.generate_bundle(&texture),
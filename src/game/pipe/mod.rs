@@ -1,10 +1,13 @@
 automod::dir!(pub "src/game/pipe");
 
-use super::{DateTime, ImageAssets};
-use crate::{GameState, SCREEN_WIDTH};
+use super::{
+    config::components::GameConfig, difficulty::components::Difficulty,
+    interpolation::components::Interpolated, store::components::Loadout, DateTime, GameRng,
+    ImageAssets, Simulation,
+};
+use crate::{despawn, BasicMath, GameState, SCREEN_WIDTH};
 use bevy::prelude::*;
 use components::Pipe;
-use flappybust::{despawn, BasicMath};
 use itertools::Itertools;
 
 /// Pipe logic.
@@ -12,36 +15,102 @@ pub struct PipePlugin;
 
 impl Plugin for PipePlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(OnEnter(GameState::Playing), spawn)
-            .add_systems(Update, moving.run_if(in_state(GameState::Playing)))
-            .add_systems(OnExit(GameState::Over), despawn::<Pipe>);
+        app.add_systems(
+            OnTransition {
+                from: GameState::Ready,
+                to: GameState::Playing,
+            },
+            spawn,
+        )
+        .add_systems(
+            FixedUpdate,
+            (moving, bring_next)
+                .chain()
+                .in_set(Simulation::Movement)
+                .run_if(in_state(GameState::Playing)),
+        )
+        .add_systems(OnExit(GameState::Over), despawn::<Pipe>)
+        .add_systems(
+            OnTransition {
+                from: GameState::Paused,
+                to: GameState::Ready,
+            },
+            despawn::<Pipe>,
+        );
     }
 }
-fn spawn(mut commands: Commands, image_assets: Res<ImageAssets>, datetime: Res<DateTime>) {
-    Pipe::genrate_self(2, true, &mut commands, &image_assets, &datetime);
+fn spawn(
+    mut commands: Commands,
+    image_assets: Res<ImageAssets>,
+    datetime: Res<DateTime>,
+    loadout: Res<Loadout>,
+    difficulty: Res<Difficulty>,
+    mut rng: ResMut<GameRng>,
+) {
+    let color = loadout.pipe_color(*datetime);
+
+    Pipe::genrate_self(
+        2,
+        SCREEN_WIDTH + Pipe::WIDTH.half(),
+        &difficulty,
+        &mut commands,
+        &image_assets,
+        color,
+        &mut rng,
+    );
 }
 
 fn moving(
+    time: Res<Time>,
     mut commands: Commands,
-    mut pipe: Query<(Entity, &mut Transform), With<Pipe>>,
-    image_assets: Res<ImageAssets>,
-    datetime: Res<DateTime>,
+    mut pipe: Query<(Entity, &mut Interpolated), With<Pipe>>,
+    difficulty: Res<Difficulty>,
+    config: Res<GameConfig>,
 ) {
-    let half_pipe_width = Pipe::WIDTH.half();
-    let half_screen_width = SCREEN_WIDTH.half();
+    let distance = config.scroll.pipes * difficulty.speed * time.delta_seconds();
 
-    for ((pipe_entity, mut pipe_transform), (flipped_pipe_entity, mut flipped_pipe_transform)) in
+    for ((pipe_entity, mut pipe_position), (flipped_pipe_entity, mut flipped_pipe_position)) in
         pipe.iter_mut().tuples()
     {
-        pipe_transform.translation.x -= 1f32;
-        flipped_pipe_transform.translation.x -= 1f32;
+        pipe_position.current.x -= distance;
+        flipped_pipe_position.current.x -= distance;
 
         // Remove pipes that are outside of screen.
-        if pipe_transform.translation.x <= -half_pipe_width - half_screen_width {
-            Pipe::genrate_self(1, false, &mut commands, &image_assets, &datetime);
-
+        if pipe_position.current.x <= -Pipe::WIDTH.half() - SCREEN_WIDTH.half() {
             commands.entity(pipe_entity).despawn();
             commands.entity(flipped_pipe_entity).despawn();
         }
     }
 }
+
+/// Bring the next pipes in once there is room for them behind the last ones.
+fn bring_next(
+    mut commands: Commands,
+    pipes: Query<&Interpolated, With<Pipe>>,
+    image_assets: Res<ImageAssets>,
+    datetime: Res<DateTime>,
+    loadout: Res<Loadout>,
+    difficulty: Res<Difficulty>,
+    mut rng: ResMut<GameRng>,
+) {
+    let entrance = SCREEN_WIDTH.half() + Pipe::WIDTH.half();
+    let last_x = pipes
+        .iter()
+        .map(|position| position.current.x)
+        .reduce(f32::max);
+    let next_x = last_x.map_or(entrance, |last_x| last_x + difficulty.spacing);
+
+    if next_x <= entrance {
+        let color = loadout.pipe_color(*datetime);
+
+        Pipe::genrate_self(
+            1,
+            next_x,
+            &difficulty,
+            &mut commands,
+            &image_assets,
+            color,
+            &mut rng,
+        );
+    }
+}
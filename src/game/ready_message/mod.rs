@@ -1,10 +1,15 @@
 automod::dir!("src/game/ready_message");
 
-use super::ImageAssets;
-use crate::GameState;
+use super::{
+    button::{
+        components::{BackButton, SpriteButton},
+        spawn_labelled,
+    },
+    FontAssets, ImageAssets,
+};
+use crate::{despawn_recursive, BasicMath, GameState, SCREEN_HEIGHT};
 use bevy::prelude::*;
 use components::ReadyMessage;
-use flappybust::despawn;
 
 /// Ready message logic.
 pub struct ReadyMessagePlugin;
@@ -12,11 +17,17 @@ pub struct ReadyMessagePlugin;
 impl Plugin for ReadyMessagePlugin {
     fn build(&self, app: &mut App) {
         app.add_systems(OnEnter(GameState::Ready), spawn)
-            .add_systems(OnEnter(GameState::Playing), despawn::<ReadyMessage>);
+            .add_systems(OnExit(GameState::Ready), despawn_recursive::<ReadyMessage>);
     }
 }
 
-fn spawn(mut commands: Commands, image_assets: Res<ImageAssets>) {
+fn spawn(mut commands: Commands, image_assets: Res<ImageAssets>, font_assets: Res<FontAssets>) {
+    let text_style = TextStyle {
+        font: font_assets.teko_bold.clone(),
+        font_size: 24f32,
+        ..default()
+    };
+
     commands.spawn((
         SpriteBundle {
             texture: image_assets.ready_message.clone(),
@@ -25,4 +36,34 @@ fn spawn(mut commands: Commands, image_assets: Res<ImageAssets>) {
         },
         ReadyMessage,
     ));
+
+    // Touched rather than flapped on, for the screens without an escape key.
+    spawn_labelled(
+        &mut commands,
+        SpriteButton {
+            color: Color::rgb(0.98, 0.6, 0.14),
+        },
+        Vec2::new(114f32, 28f32),
+        Transform::from_xyz(0f32, 70f32 - SCREEN_HEIGHT.half(), 0.5),
+        Text::from_section("ESC: MENU", text_style.clone()),
+    )
+    .insert((BackButton, ReadyMessage));
+
+    commands.spawn((
+        Text2dBundle {
+            text: Text::from_section("S: STORE   G: GALLERY   L: LEADERBOARD", text_style.clone()),
+            transform: Transform::from_xyz(0f32, 40f32 - SCREEN_HEIGHT.half(), 0.5),
+            ..default()
+        },
+        ReadyMessage,
+    ));
+
+    commands.spawn((
+        Text2dBundle {
+            text: Text::from_section("A: AUTOPILOT   C: CONTROLS   V: AUDIO", text_style),
+            transform: Transform::from_xyz(0f32, 14f32 - SCREEN_HEIGHT.half(), 0.5),
+            ..default()
+        },
+        ReadyMessage,
+    ));
 }
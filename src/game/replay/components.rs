@@ -0,0 +1,63 @@
+use crate::game::difficulty::components::Preset;
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::fs;
+
+/// Everything needed to play a run again:
+/// the seed it started with and the steps on which the bird flapped.
+#[derive(Default, Clone, Serialize, Deserialize)]
+pub struct Replay {
+    pub seed: u64,
+    /// Difficulty the run was played on.
+    #[serde(default)]
+    pub preset: Preset,
+    /// Steps spent on the ready screen before the run started.
+    pub start: u64,
+    /// Steps since the run started on which the bird flapped.
+    pub flaps: Vec<u64>,
+}
+
+impl Replay {
+    /// Replay of the run that just ended.
+    pub(crate) fn record(
+        seed: u64,
+        preset: Preset,
+        ticks: &RunTicks,
+        recording: &Recording,
+    ) -> Self {
+        Replay {
+            seed,
+            preset,
+            start: ticks.ready,
+            flaps: recording.0.clone(),
+        }
+    }
+
+    /// Read a replay file shared by another player.
+    pub fn load(path: &str) -> Result<Self, String> {
+        let contents = fs::read_to_string(path).map_err(|err| err.to_string())?;
+
+        ron::from_str(&contents).map_err(|err| err.to_string())
+    }
+}
+
+/// Simulation steps elapsed in the current round.
+#[derive(Resource, Default, Clone, Copy)]
+pub struct RunTicks {
+    pub ready: u64,
+    pub playing: u64,
+}
+
+/// Flaps of the current run, recorded as they happen.
+#[derive(Resource, Default)]
+pub(crate) struct Recording(pub Vec<u64>);
+
+/// Replay played instead of reading the player input.
+#[derive(Resource)]
+pub struct Playback(pub Replay);
+
+impl Playback {
+    pub fn flaps_on(&self, tick: u64) -> bool {
+        self.0.flaps.binary_search(&tick).is_ok()
+    }
+}
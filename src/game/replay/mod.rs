@@ -0,0 +1,129 @@
+automod::dir!(pub "src/game/replay");
+
+use super::{
+    bird::events::{FlapAction, FlapEvent},
+    collision::events::CollisionEvent,
+    LaunchOptions, Simulation,
+};
+use crate::GameState;
+use bevy::prelude::*;
+use components::*;
+
+/// Replay logic.
+pub struct ReplayPlugin;
+
+impl Plugin for ReplayPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<RunTicks>()
+            .init_resource::<Recording>()
+            .add_systems(OnEnter(GameState::AssetLoading), load)
+            .add_systems(
+                FixedUpdate,
+                (
+                    feed.in_set(Simulation::Input)
+                        .run_if(in_state(GameState::Playing).and_then(resource_exists::<Playback>)),
+                    (record, end_on_collision)
+                        .after(Simulation::Scoring)
+                        .run_if(in_state(GameState::Playing)),
+                    (count_ready, hold_start.run_if(resource_exists::<Playback>))
+                        .chain()
+                        .after(Simulation::Scoring)
+                        .run_if(in_state(GameState::Ready)),
+                ),
+            )
+            .add_systems(
+                Update,
+                start.run_if(in_state(GameState::Ready).and_then(resource_exists::<Playback>)),
+            )
+            .add_systems(OnExit(GameState::AssetLoading), reset)
+            .add_systems(OnExit(GameState::Over), reset)
+            .add_systems(
+                OnTransition {
+                    from: GameState::Paused,
+                    to: GameState::Ready,
+                },
+                reset,
+            );
+    }
+}
+
+fn load(mut commands: Commands, mut options: ResMut<LaunchOptions>) {
+    let Some(path) = options.replay.clone() else {
+        return;
+    };
+
+    match Replay::load(&path) {
+        Ok(replay) => {
+            options.seed = Some(replay.seed);
+            options.difficulty = Some(replay.preset);
+            commands.insert_resource(Playback(replay));
+        }
+        Err(err) => warn!("Failed to load replay {path}: {err}"),
+    }
+}
+
+fn reset(mut ticks: ResMut<RunTicks>, mut recording: ResMut<Recording>) {
+    *ticks = RunTicks::default();
+    recording.0.clear();
+}
+
+fn count_ready(mut ticks: ResMut<RunTicks>) {
+    ticks.ready += 1;
+}
+
+fn record(
+    mut flap_event: EventReader<FlapEvent>,
+    mut ticks: ResMut<RunTicks>,
+    mut recording: ResMut<Recording>,
+) {
+    if flap_event.read().count() > 0 {
+        recording.0.push(ticks.playing);
+    }
+
+    ticks.playing += 1;
+}
+
+fn feed(playback: Res<Playback>, ticks: Res<RunTicks>, mut flap_action: EventWriter<FlapAction>) {
+    if playback.flaps_on(ticks.playing) {
+        flap_action.send_default();
+    }
+}
+
+fn start(
+    playback: Res<Playback>,
+    ticks: Res<RunTicks>,
+    mut next_state: ResMut<NextState<GameState>>,
+) {
+    if ticks.ready >= playback.0.start {
+        next_state.set(GameState::Playing);
+    }
+}
+
+/// Stop simulating the ready screen once the replayed run is due to start.
+fn hold_start(playback: Res<Playback>, ticks: Res<RunTicks>, mut time: ResMut<Time<Fixed>>) {
+    if ticks.ready >= playback.0.start {
+        skip_remaining_steps(&mut time);
+    }
+}
+
+/// Stop simulating the frame on the step the bird crashes,
+/// so that a run never lasts a few more steps depending on the frame rate.
+fn end_on_collision(
+    mut collision_event: EventReader<CollisionEvent>,
+    mut time: ResMut<Time<Fixed>>,
+) {
+    if collision_event.is_empty() {
+        return;
+    }
+
+    collision_event.clear();
+    skip_remaining_steps(&mut time);
+}
+
+/// State transitions only happen between frames, so steps left in the
+/// current frame would run in the wrong state.
+fn skip_remaining_steps(time: &mut Time<Fixed>) {
+    let overstep = time.overstep();
+
+    time.discard_overstep(overstep);
+}
@@ -1,12 +1,24 @@
+use crate::ternary;
 use bevy::prelude::*;
 use bevy_asset_loader::prelude::*;
-use flappybust::ternary;
-use rand::{distributions::Standard, prelude::Distribution, random, Rng};
+use rand::{
+    distributions::Standard, prelude::Distribution, rngs::StdRng, Rng, RngCore, SeedableRng,
+};
+use serde::{Deserialize, Serialize};
 
-#[derive(AssetCollection, Resource)]
+#[derive(AssetCollection, Resource, Default)]
 pub struct AudioAssets {
     #[asset(key = "theme")]
     pub theme: Handle<AudioSource>,
+    /// Calm loop behind the menus.
+    #[asset(key = "menu")]
+    pub menu: Handle<AudioSource>,
+    /// Played once when the bird dies.
+    #[asset(key = "sting")]
+    pub sting: Handle<AudioSource>,
+    /// Beat played over the theme, louder as the score grows.
+    #[asset(key = "layer")]
+    pub layer: Handle<AudioSource>,
     #[asset(key = "die")]
     pub die: Handle<AudioSource>,
     #[asset(key = "ding")]
@@ -23,13 +35,13 @@ pub struct AudioAssets {
     pub wing: Handle<AudioSource>,
 }
 
-#[derive(AssetCollection, Resource)]
+#[derive(AssetCollection, Resource, Default)]
 pub struct FontAssets {
     #[asset(key = "teko_bold")]
     pub teko_bold: Handle<Font>,
 }
 
-#[derive(AssetCollection, Resource)]
+#[derive(AssetCollection, Resource, Default)]
 pub struct ImageAssets {
     #[asset(key = "base")]
     pub base: Handle<Image>,
@@ -39,6 +51,8 @@ pub struct ImageAssets {
     pub bg_day: Handle<Image>,
     #[asset(key = "bg_night")]
     pub bg_night: Handle<Image>,
+    #[asset(key = "bg_dusk")]
+    pub bg_dusk: Handle<Image>,
 
     // birds
     #[asset(key = "bird_soul")]
@@ -50,16 +64,33 @@ pub struct ImageAssets {
     pub red_birds: Vec<Handle<Image>>,
     #[asset(key = "yellow_birds", collection(typed))]
     pub yellow_birds: Vec<Handle<Image>>,
+    #[asset(key = "purple_birds", collection(typed))]
+    pub purple_birds: Vec<Handle<Image>>,
+    #[asset(key = "green_birds", collection(typed))]
+    pub green_birds: Vec<Handle<Image>>,
 
     // pipes
     #[asset(key = "green_pipe")]
     pub green_pipe: Handle<Image>,
     #[asset(key = "red_pipe")]
     pub red_pipe: Handle<Image>,
+    #[asset(key = "blue_pipe")]
+    pub blue_pipe: Handle<Image>,
+
+    #[asset(key = "coin")]
+    pub coin: Handle<Image>,
 
     #[asset(key = "ready_message")]
     pub ready_message: Handle<Image>,
 
+    // game over
+    #[asset(key = "game_over")]
+    pub game_over: Handle<Image>,
+    #[asset(key = "scoreboard")]
+    pub scoreboard: Handle<Image>,
+    #[asset(key = "restart_btn")]
+    pub restart_btn: Handle<Image>,
+
     #[asset(key = "bronze_medal")]
     pub bronze_medal: Handle<Image>,
     #[asset(key = "silver_medal")]
@@ -71,8 +102,9 @@ pub struct ImageAssets {
 }
 
 /// Represent time of a day in game.
-#[derive(Clone, Copy, Resource, PartialEq)]
+#[derive(Clone, Copy, Resource, PartialEq, Eq, Default, Debug, Serialize, Deserialize)]
 pub enum DateTime {
+    #[default]
     Day,
     Night,
 }
@@ -83,8 +115,41 @@ impl Distribution<DateTime> for Standard {
     }
 }
 
-impl Default for DateTime {
-    fn default() -> Self {
-        random::<Self>()
+/// Random number generator every system draws from,
+/// so that the same seed always reproduces the same run.
+#[derive(Resource)]
+pub struct GameRng {
+    seed: u64,
+    rng: StdRng,
+}
+
+impl GameRng {
+    pub fn new(seed: u64) -> Self {
+        GameRng {
+            seed,
+            rng: StdRng::seed_from_u64(seed),
+        }
+    }
+
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+}
+
+impl RngCore for GameRng {
+    fn next_u32(&mut self) -> u32 {
+        self.rng.next_u32()
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.rng.next_u64()
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        self.rng.fill_bytes(dest)
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand::Error> {
+        self.rng.try_fill_bytes(dest)
     }
 }
@@ -0,0 +1,61 @@
+use crate::game::{
+    audio::components::Mixer, controls::components::Bindings, difficulty::components::Preset,
+    game_over::components::MedalType, leaderboard::components::Leaderboard,
+    store::components::Item, store::components::Loadout,
+};
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// Number of medals earned for each type.
+#[derive(Default, Clone, Copy, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[serde(default)]
+pub struct MedalCount {
+    pub bronze: usize,
+    pub silver: usize,
+    pub gold: usize,
+    pub platinum: usize,
+}
+
+impl MedalCount {
+    pub fn add(&mut self, medal: MedalType) {
+        match medal {
+            MedalType::Bronze => self.bronze += 1,
+            MedalType::Silver => self.silver += 1,
+            MedalType::Gold => self.gold += 1,
+            MedalType::Platinum => self.platinum += 1,
+        }
+    }
+}
+
+/// Lifetime statistics of the player.
+#[derive(Resource, Default, Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Statistics {
+    /// Best score of a save from before the leaderboard, which takes it over on load.
+    #[serde(rename = "best_score", skip_serializing)]
+    pub(super) old_best_score: usize,
+    pub games_played: usize,
+    pub pipes_passed: usize,
+    pub medals: MedalCount,
+    pub flaps: usize,
+}
+
+/// Difficulty read from the save file, written back instead of one forced at launch.
+#[derive(Resource, Default, Clone, Copy)]
+pub(super) struct SavedPreset(pub Preset);
+
+/// Everything that is written to the save file.
+#[derive(Default, Serialize, Deserialize)]
+#[serde(default)]
+pub(super) struct SaveData {
+    pub statistics: Statistics,
+    pub coins: usize,
+    pub inventory: Vec<Item>,
+    pub loadout: Loadout,
+    pub preset: Preset,
+    /// Ids of the unlocked achievements.
+    pub achievements: Vec<String>,
+    pub leaderboard: Leaderboard,
+    pub bindings: Bindings,
+    pub mixer: Mixer,
+}
@@ -0,0 +1,220 @@
+automod::dir!(pub "src/game/save");
+
+use super::{
+    achievement::components::Unlocked,
+    audio::components::Mixer,
+    autopilot,
+    bird::{components::BirdColor, events::FlapEvent},
+    coin::components::Wallet,
+    config::components::GameConfig,
+    controls::components::Bindings,
+    difficulty::components::{DifficultyCurve, Preset, Presets},
+    game_over::components::MedalType,
+    leaderboard::components::{Entry, Leaderboard},
+    replay::components::{Playback, Recording, Replay, RunTicks},
+    score::components::Score,
+    storage,
+    store::components::{Inventory, Loadout},
+    DateTime, GameRng, LaunchOptions,
+};
+use crate::{ternary, GameState};
+use bevy::{ecs::system::SystemParam, prelude::*};
+use components::*;
+
+const SAVE_FILE: &str = "save.ron";
+const LAST_RUN_FILE: &str = "last_run.ron";
+const CUSTOM_DIFFICULTY_FILE: &str = "custom_difficulty.ron";
+
+/// Save logic.
+pub struct SavePlugin;
+
+impl Plugin for SavePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<Statistics>()
+            .init_resource::<SavedPreset>()
+            .add_systems(
+                OnEnter(GameState::AssetLoading),
+                (load, load_custom_difficulty),
+            )
+            .add_systems(
+                Update,
+                (
+                    count_flaps.run_if(
+                        in_state(GameState::Playing)
+                            .and_then(not(autopilot::has_flown))
+                            .and_then(not(resource_exists::<Playback>)),
+                    ),
+                    save.run_if(
+                        in_state(GameState::Ready)
+                            .or_else(in_state(GameState::Menu))
+                            .and_then(resource_changed::<Preset>),
+                    ),
+                    save.run_if(
+                        in_state(GameState::Over).and_then(resource_changed::<Leaderboard>),
+                    ),
+                    save.run_if(
+                        not(in_state(GameState::AssetLoading)).and_then(resource_changed::<Mixer>),
+                    ),
+                ),
+            )
+            .add_systems(
+                OnEnter(GameState::Over),
+                // A replay played again is neither progress nor a new last run.
+                ((record_run, save).chain(), save_replay)
+                    .run_if(not(autopilot::has_flown).and_then(not(resource_exists::<Playback>))),
+            )
+            .add_systems(OnExit(GameState::Store), save)
+            .add_systems(OnExit(GameState::Controls), save);
+    }
+}
+
+fn load(mut commands: Commands) {
+    let mut data = match storage::read(SAVE_FILE) {
+        Ok(contents) => ron::from_str::<SaveData>(&contents).unwrap_or_else(|err| {
+            warn!("Ignoring corrupted save file: {err}");
+            SaveData::default()
+        }),
+        Err(_) => return,
+    };
+
+    // Saves from before the leaderboard only have a best score.
+    if data.leaderboard.entries.is_empty() && data.statistics.old_best_score > 0 {
+        let score = data.statistics.old_best_score;
+
+        data.leaderboard.insert(Entry {
+            name: "PLAYER".to_string(),
+            score,
+            date: String::new(),
+            bird: BirdColor::Yellow,
+            datetime: DateTime::Day,
+            medal: MedalType::from_score(score, &GameConfig::default().medals),
+            preset: Preset::Normal,
+        });
+    }
+
+    let mut inventory = Inventory::default();
+    inventory.0.extend(data.inventory);
+
+    commands.insert_resource(data.statistics);
+    commands.insert_resource(Wallet {
+        balance: data.coins,
+        ..default()
+    });
+    commands.insert_resource(inventory);
+    commands.insert_resource(data.loadout);
+    commands.insert_resource(data.preset);
+    commands.insert_resource(SavedPreset(data.preset));
+    commands.insert_resource(Unlocked(data.achievements.into_iter().collect()));
+    commands.insert_resource(data.leaderboard);
+    commands.insert_resource(data.bindings.complete());
+    commands.insert_resource(data.mixer);
+}
+
+/// Read the custom difficulty, or write the normal one for the player to tune.
+fn load_custom_difficulty(mut presets: ResMut<Presets>) {
+    let contents = match storage::read(CUSTOM_DIFFICULTY_FILE) {
+        Ok(contents) => contents,
+        Err(_) => {
+            let result = ron::ser::to_string_pretty(presets.get(Preset::Custom), default())
+                .map_err(|err| err.to_string())
+                .and_then(|contents| {
+                    storage::write(CUSTOM_DIFFICULTY_FILE, &contents).map_err(|err| err.to_string())
+                });
+
+            if let Err(err) = result {
+                warn!("Failed to write custom difficulty file: {err}");
+            }
+
+            return;
+        }
+    };
+
+    let curve = ron::from_str::<DifficultyCurve>(&contents)
+        .map_err(|err| err.to_string())
+        .and_then(|curve| curve.validate().map(|()| curve));
+
+    match curve {
+        Ok(curve) => {
+            presets.0.insert(Preset::Custom, curve);
+        }
+        Err(err) => warn!("Ignoring invalid custom difficulty file: {err}"),
+    }
+}
+
+/// Resources written to the save file.
+#[derive(SystemParam)]
+struct Progress<'w> {
+    statistics: Res<'w, Statistics>,
+    wallet: Res<'w, Wallet>,
+    inventory: Res<'w, Inventory>,
+    loadout: Res<'w, Loadout>,
+    preset: Res<'w, Preset>,
+    saved_preset: Res<'w, SavedPreset>,
+    options: Res<'w, LaunchOptions>,
+    unlocked: Res<'w, Unlocked>,
+    leaderboard: Res<'w, Leaderboard>,
+    bindings: Res<'w, Bindings>,
+    mixer: Res<'w, Mixer>,
+}
+
+fn save(progress: Progress) {
+    let mut achievements: Vec<String> = progress.unlocked.0.iter().cloned().collect();
+    achievements.sort();
+
+    let data = SaveData {
+        statistics: progress.statistics.clone(),
+        coins: progress.wallet.balance,
+        inventory: progress.inventory.0.iter().copied().collect(),
+        loadout: *progress.loadout,
+        // A difficulty given on the command line or by a replay only lasts for this launch.
+        preset: ternary!(
+            progress.options.difficulty.is_some(),
+            progress.saved_preset.0,
+            *progress.preset
+        ),
+        achievements,
+        leaderboard: progress.leaderboard.clone(),
+        bindings: progress.bindings.clone(),
+        mixer: *progress.mixer,
+    };
+
+    let result = ron::ser::to_string_pretty(&data, default())
+        .map_err(|err| err.to_string())
+        .and_then(|contents| storage::write(SAVE_FILE, &contents).map_err(|err| err.to_string()));
+
+    if let Err(err) = result {
+        warn!("Failed to write save file: {err}");
+    }
+}
+
+fn save_replay(
+    rng: Res<GameRng>,
+    ticks: Res<RunTicks>,
+    recording: Res<Recording>,
+    preset: Res<Preset>,
+) {
+    let replay = Replay::record(rng.seed(), *preset, &ticks, &recording);
+
+    let result = ron::to_string(&replay)
+        .map_err(|err| err.to_string())
+        .and_then(|contents| {
+            storage::write(LAST_RUN_FILE, &contents).map_err(|err| err.to_string())
+        });
+
+    if let Err(err) = result {
+        warn!("Failed to write replay file: {err}");
+    }
+}
+
+fn count_flaps(mut flap_event: EventReader<FlapEvent>, mut statistics: ResMut<Statistics>) {
+    statistics.flaps += flap_event.read().count();
+}
+
+fn record_run(score: Res<Score>, config: Res<GameConfig>, mut statistics: ResMut<Statistics>) {
+    statistics.games_played += 1;
+    statistics.pipes_passed += score.current;
+
+    if let Some(medal) = MedalType::from_score(score.current, &config.medals) {
+        statistics.medals.add(medal);
+    }
+}
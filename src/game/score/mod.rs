@@ -1,13 +1,14 @@
 automod::dir!(pub "src/game/score");
 
 use super::{
-    audio::events::AudioEvent, bird::components::Bird, game_over::events::ScoreboardDisplayed,
-    pipe::components::Pipe, AudioAssets, FontAssets, GameState,
+    audio::events::AudioEvent, bird::components::Bird, difficulty::components::Preset,
+    game_over::events::ScoreboardDisplayed, interpolation::components::Interpolated,
+    leaderboard::components::Leaderboard, pipe::components::Pipe, AudioAssets, FontAssets,
+    GameState, Simulation,
 };
-use crate::SCREEN_HEIGHT;
+use crate::{despawn, BasicMath, Switcher, SCREEN_HEIGHT};
 use bevy::{prelude::*, sprite::Anchor::TopCenter};
 use components::*;
-use flappybust::{despawn, BasicMath, Switcher};
 use itertools::Itertools;
 
 pub struct ScorePlugin;
@@ -15,52 +16,58 @@ pub struct ScorePlugin;
 impl Plugin for ScorePlugin {
     fn build(&self, app: &mut App) {
         app.init_resource::<Score>()
-            .add_systems(OnEnter(GameState::Playing), spawn_current_score)
+            .add_systems(
+                OnTransition {
+                    from: GameState::Ready,
+                    to: GameState::Playing,
+                },
+                spawn_current_score,
+            )
             .add_systems(
                 Update,
                 (
-                    (record, display_current_score).run_if(in_state(GameState::Playing)),
+                    display_current_score.run_if(in_state(GameState::Playing)),
                     display_scoreboard_score.run_if(in_state(GameState::Over)),
                 ),
             )
+            .add_systems(
+                FixedUpdate,
+                record
+                    .in_set(Simulation::Scoring)
+                    .run_if(in_state(GameState::Playing)),
+            )
             .add_systems(OnEnter(GameState::Over), despawn::<CurrentScore>)
-            .add_systems(OnExit(GameState::Over), despawn::<ScoreboardScore>);
+            .add_systems(OnExit(GameState::Over), despawn::<ScoreboardScore>)
+            .add_systems(
+                OnTransition {
+                    from: GameState::Paused,
+                    to: GameState::Ready,
+                },
+                despawn::<CurrentScore>,
+            );
     }
 }
 
-fn spawn_current_score(
-    mut commands: Commands,
-    font_assets: Res<FontAssets>,
-    prev_score: Res<Score>,
-) {
-    let score = Score {
-        current: 0,
-        ..*prev_score
-    };
+fn spawn_current_score(mut commands: Commands, font_assets: Res<FontAssets>) {
+    let score = Score::default();
 
-    commands
-        .spawn(SpriteBundle {
-            transform: Transform::from_xyz(0f32, SCREEN_HEIGHT.half(), 0.2),
-            ..default()
-        })
-        .with_children(|builder| {
-            builder.spawn((
-                Text2dBundle {
-                    text: Text::from_section(
-                        score.current.to_string(),
-                        TextStyle {
-                            font: font_assets.teko_bold.clone(),
-                            font_size: 64f32,
-                            ..default()
-                        },
-                    )
-                    .with_justify(JustifyText::Center),
-                    text_anchor: TopCenter,
+    commands.spawn((
+        Text2dBundle {
+            text: Text::from_section(
+                score.current.to_string(),
+                TextStyle {
+                    font: font_assets.teko_bold.clone(),
+                    font_size: 64f32,
                     ..default()
                 },
-                CurrentScore,
-            ));
-        });
+            )
+            .with_justify(JustifyText::Center),
+            text_anchor: TopCenter,
+            transform: Transform::from_xyz(0f32, SCREEN_HEIGHT.half(), 0.2),
+            ..default()
+        },
+        CurrentScore,
+    ));
 
     commands.insert_resource(score);
 }
@@ -81,8 +88,12 @@ fn display_scoreboard_score(
     score: Res<Score>,
     font_assets: Res<FontAssets>,
     mut scoreboard_displayed: EventReader<ScoreboardDisplayed>,
+    scoreboard_score: Query<(), With<ScoreboardScore>>,
+    leaderboard: Res<Leaderboard>,
+    preset: Res<Preset>,
 ) {
-    if scoreboard_displayed.is_empty() {
+    // The scoreboard keeps notifying while it stays in place.
+    if scoreboard_displayed.is_empty() || !scoreboard_score.is_empty() {
         return;
     }
 
@@ -92,49 +103,25 @@ fn display_scoreboard_score(
         ..default()
     };
 
-    let box_size = Vec2::new(68f32, 20f32);
-
-    commands
-        .spawn(SpriteBundle {
+    commands.spawn((
+        Text2dBundle {
+            text: Text::from_section(score.current.to_string(), text_style.clone())
+                .with_justify(JustifyText::Center),
             transform: Transform::from_xyz(63.8f32, 67f32, 0.3),
-            sprite: Sprite {
-                color: Color::NONE,
-                custom_size: Some(box_size),
-                ..default()
-            },
             ..default()
-        })
-        .with_children(|builder| {
-            builder.spawn((
-                Text2dBundle {
-                    text: Text::from_section(score.current.to_string(), text_style.clone())
-                        .with_justify(JustifyText::Center),
-                    ..default()
-                },
-                ScoreboardScore,
-            ));
-        });
-
-    commands
-        .spawn(SpriteBundle {
+        },
+        ScoreboardScore,
+    ));
+
+    commands.spawn((
+        Text2dBundle {
+            text: Text::from_section(leaderboard.best(*preset).to_string(), text_style)
+                .with_justify(JustifyText::Center),
             transform: Transform::from_xyz(63.8f32, 18f32, 0.3),
-            sprite: Sprite {
-                color: Color::NONE,
-                custom_size: Some(box_size),
-                ..default()
-            },
             ..default()
-        })
-        .with_children(|builder| {
-            builder.spawn((
-                Text2dBundle {
-                    text: Text::from_section(score.highest.to_string(), text_style)
-                        .with_justify(JustifyText::Center),
-                    ..default()
-                },
-                ScoreboardScore,
-            ));
-        });
+        },
+        ScoreboardScore,
+    ));
 
     scoreboard_displayed.clear();
 }
@@ -142,21 +129,18 @@ fn display_scoreboard_score(
 fn record(
     audio_assets: Res<AudioAssets>,
     mut audio_event: EventWriter<AudioEvent>,
-    bird: Query<&Transform, With<Bird>>,
-    mut pipe: Query<(&mut Pipe, &Transform)>,
+    bird: Query<&Interpolated, With<Bird>>,
+    mut pipe: Query<(&mut Pipe, &Interpolated)>,
     mut score: ResMut<Score>,
 ) {
-    let bird_transform = bird.single();
+    let bird_position = bird.single();
 
-    for ((mut pipe, pipe_transform), _) in pipe.iter_mut().tuples() {
+    for ((mut pipe, pipe_position), _) in pipe.iter_mut().tuples() {
         // Increase score each time the bird has passed the pipe.
-        if bird_transform.translation.x + Bird::WIDTH.half() > pipe_transform.translation.x
-            && !pipe.hidden
-        {
+        if bird_position.current.x + Bird::WIDTH.half() > pipe_position.current.x && !pipe.hidden {
             score.current += 1;
-            score.highest = score.current.max(score.highest);
 
-            audio_event.send(AudioEvent::new(&audio_assets.score, false));
+            audio_event.send(AudioEvent::sfx(&audio_assets.score));
 
             // Prevent the score from increasing twice on frame changing too fast.
             pipe.hidden.on();
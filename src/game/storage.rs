@@ -0,0 +1,61 @@
+//! Read and write small text files that must survive between launches.
+//!
+//! Files live in the platform data directory on native builds
+//! and in the browser `localStorage` on the web build.
+
+#[cfg(not(target_arch = "wasm32"))]
+mod native {
+    use std::{fs, io, path::PathBuf};
+
+    fn path(name: &str) -> io::Result<PathBuf> {
+        let dir = dirs::data_dir()
+            .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, "no data directory"))?
+            .join("flappybust");
+
+        fs::create_dir_all(&dir)?;
+
+        Ok(dir.join(name))
+    }
+
+    pub fn read(name: &str) -> io::Result<String> {
+        fs::read_to_string(path(name)?)
+    }
+
+    pub fn write(name: &str, contents: &str) -> io::Result<()> {
+        fs::write(path(name)?, contents)
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+mod web {
+    use std::io;
+
+    fn local_storage() -> io::Result<web_sys::Storage> {
+        web_sys::window()
+            .and_then(|window| window.local_storage().ok().flatten())
+            .ok_or_else(|| io::Error::new(io::ErrorKind::Unsupported, "no local storage"))
+    }
+
+    fn key(name: &str) -> String {
+        format!("flappybust/{name}")
+    }
+
+    pub fn read(name: &str) -> io::Result<String> {
+        local_storage()?
+            .get_item(&key(name))
+            .ok()
+            .flatten()
+            .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, name.to_string()))
+    }
+
+    pub fn write(name: &str, contents: &str) -> io::Result<()> {
+        local_storage()?
+            .set_item(&key(name), contents)
+            .map_err(|_| io::Error::new(io::ErrorKind::Other, "local storage is full"))
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+pub use native::*;
+#[cfg(target_arch = "wasm32")]
+pub use web::*;
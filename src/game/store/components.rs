@@ -0,0 +1,197 @@
+use crate::game::{
+    background::components::Scenery, bird::components::BirdColor, pipe::components::PipeColor,
+    DateTime, GameRng,
+};
+use bevy::{prelude::*, utils::HashSet};
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+
+/// Cosmetic that can be bought from the store.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Serialize, Deserialize)]
+pub enum Item {
+    Bird(BirdColor),
+    Pipe(PipeColor),
+    Background(Scenery),
+}
+
+impl Item {
+    pub fn price(self) -> usize {
+        match self {
+            Item::Bird(BirdColor::Purple) => 25,
+            Item::Bird(BirdColor::Green) => 50,
+            Item::Pipe(PipeColor::Blue) => 40,
+            Item::Background(Scenery::Dusk) => 60,
+            _ => 0,
+        }
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Item::Bird(color) => color.name(),
+            Item::Pipe(color) => color.name(),
+            Item::Background(scenery) => scenery.name(),
+        }
+    }
+}
+
+/// Group of items shown on the same store row.
+#[derive(Component, Clone, Copy, PartialEq, Eq)]
+pub enum Category {
+    Bird,
+    Pipe,
+    Background,
+}
+
+impl Category {
+    pub const ALL: [Category; 3] = [Self::Bird, Self::Pipe, Self::Background];
+
+    pub fn row(self) -> usize {
+        Category::ALL.iter().position(|c| *c == self).unwrap()
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Category::Bird => "BIRD",
+            Category::Pipe => "PIPE",
+            Category::Background => "BACKGROUND",
+        }
+    }
+
+    /// Items of this category, `None` stands for the default look.
+    pub fn items(self) -> Vec<Option<Item>> {
+        let items = match self {
+            Category::Bird => [
+                BirdColor::Yellow,
+                BirdColor::Red,
+                BirdColor::Blue,
+                BirdColor::Purple,
+                BirdColor::Green,
+            ]
+            .map(Item::Bird)
+            .to_vec(),
+            Category::Pipe => [PipeColor::Green, PipeColor::Red, PipeColor::Blue]
+                .map(Item::Pipe)
+                .to_vec(),
+            Category::Background => [Scenery::Day, Scenery::Night, Scenery::Dusk]
+                .map(Item::Background)
+                .to_vec(),
+        };
+
+        std::iter::once(None)
+            .chain(items.into_iter().map(Some))
+            .collect()
+    }
+
+    /// Name of the default look.
+    pub fn default_name(self) -> &'static str {
+        match self {
+            Category::Bird => "RANDOM",
+            Category::Pipe | Category::Background => "AUTO",
+        }
+    }
+}
+
+/// Items owned by the player.
+#[derive(Resource)]
+pub struct Inventory(pub HashSet<Item>);
+
+impl Default for Inventory {
+    fn default() -> Self {
+        let free_items = Category::ALL
+            .into_iter()
+            .flat_map(Category::items)
+            .flatten()
+            .filter(|item| item.price() == 0);
+
+        Inventory(free_items.collect())
+    }
+}
+
+impl Inventory {
+    pub fn owns(&self, item: Item) -> bool {
+        self.0.contains(&item)
+    }
+}
+
+/// Equipped cosmetics, `None` keeps the default look.
+#[derive(Resource, Default, Clone, Copy, Serialize, Deserialize)]
+pub struct Loadout {
+    pub bird: Option<BirdColor>,
+    pub pipe: Option<PipeColor>,
+    pub background: Option<Scenery>,
+}
+
+impl Loadout {
+    /// The random color is always drawn so runs with the same seed
+    /// stay identical whatever the player has equipped.
+    pub fn bird_color(&self, rng: &mut GameRng) -> BirdColor {
+        let random_color = rng.gen();
+
+        self.bird.unwrap_or(random_color)
+    }
+
+    pub fn pipe_color(&self, datetime: DateTime) -> PipeColor {
+        self.pipe.unwrap_or(datetime.into())
+    }
+
+    pub fn scenery(&self, datetime: DateTime) -> Scenery {
+        self.background.unwrap_or(datetime.into())
+    }
+
+    pub fn equipped(&self, category: Category) -> Option<Item> {
+        match category {
+            Category::Bird => self.bird.map(Item::Bird),
+            Category::Pipe => self.pipe.map(Item::Pipe),
+            Category::Background => self.background.map(Item::Background),
+        }
+    }
+
+    pub fn equip(&mut self, category: Category, item: Option<Item>) {
+        match (category, item) {
+            (Category::Bird, Some(Item::Bird(color))) => self.bird = Some(color),
+            (Category::Pipe, Some(Item::Pipe(color))) => self.pipe = Some(color),
+            (Category::Background, Some(Item::Background(scenery))) => {
+                self.background = Some(scenery)
+            }
+            (Category::Bird, _) => self.bird = None,
+            (Category::Pipe, _) => self.pipe = None,
+            (Category::Background, _) => self.background = None,
+        }
+    }
+}
+
+/// Highlighted row and browsed item of each row.
+#[derive(Resource, Default)]
+pub struct StoreCursor {
+    pub row: usize,
+    pub items: [usize; 3],
+}
+
+impl StoreCursor {
+    pub fn category(&self) -> Category {
+        Category::ALL[self.row]
+    }
+
+    pub fn item(&self, category: Category) -> Option<Item> {
+        category.items()[self.items[category.row()]]
+    }
+
+    pub fn browse(&mut self, step: isize) {
+        let len = self.category().items().len() as isize;
+        let index = &mut self.items[self.row];
+
+        *index = (*index as isize + step).rem_euclid(len) as usize;
+    }
+}
+
+#[derive(Component)]
+pub struct StoreScreen;
+
+#[derive(Component)]
+pub(super) struct StoreBalance;
+
+#[derive(Component)]
+pub(super) struct StorePreview(pub Category);
+
+#[derive(Component)]
+pub(super) struct StoreCaption(pub Category);
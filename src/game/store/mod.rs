@@ -0,0 +1,368 @@
+automod::dir!(pub "src/game/store");
+
+use super::{
+    audio::events::AudioEvent,
+    background::components::Scenery,
+    bird::components::BirdColor,
+    button::{
+        components::{BackButton, SpriteButton},
+        spawn_labelled, Back, ButtonSystem,
+    },
+    coin::components::Wallet,
+    controls::components::Controls,
+    menu::components::SettingsReturn,
+    pipe::components::PipeColor,
+    AudioAssets, DateTime, FontAssets, ImageAssets,
+};
+use crate::{despawn_recursive, ternary, BasicMath, GameState, SCREEN_HEIGHT, SCREEN_WIDTH};
+use bevy::prelude::*;
+use components::*;
+
+/// Store logic.
+pub struct StorePlugin;
+
+impl Plugin for StorePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<Inventory>()
+            .init_resource::<Loadout>()
+            .init_resource::<StoreCursor>()
+            .add_systems(
+                Update,
+                (
+                    open.run_if(in_state(GameState::Ready)),
+                    (
+                        navigate.after(ButtonSystem),
+                        purchase,
+                        (
+                            refresh_previews,
+                            highlight_row,
+                            display_balance,
+                            describe_items,
+                        ),
+                    )
+                        .chain()
+                        .run_if(in_state(GameState::Store)),
+                ),
+            )
+            .add_systems(OnEnter(GameState::Store), spawn)
+            .add_systems(OnExit(GameState::Store), despawn_recursive::<StoreScreen>);
+    }
+}
+
+/// Vertical position of the center of a store row.
+fn row_y(row: usize) -> f32 {
+    100f32 - 115f32 * row as f32
+}
+
+fn open(
+    controls: Res<Controls>,
+    mut settings_return: ResMut<SettingsReturn>,
+    mut next_state: ResMut<NextState<GameState>>,
+) {
+    if controls.open_store {
+        settings_return.0 = GameState::Ready;
+        next_state.set(GameState::Store);
+    }
+}
+
+fn spawn(
+    mut commands: Commands,
+    font_assets: Res<FontAssets>,
+    image_assets: Res<ImageAssets>,
+    loadout: Res<Loadout>,
+    mut cursor: ResMut<StoreCursor>,
+) {
+    // Start browsing from the equipped items.
+    for (i, category) in Category::ALL.into_iter().enumerate() {
+        let equipped = loadout.equipped(category);
+
+        cursor.items[i] = category
+            .items()
+            .iter()
+            .position(|item| *item == equipped)
+            .unwrap_or_default();
+    }
+
+    let text_style = TextStyle {
+        font: font_assets.teko_bold.clone(),
+        font_size: 24f32,
+        ..default()
+    };
+
+    let screen = commands
+        .spawn((
+            SpriteBundle {
+                transform: Transform::from_xyz(0f32, 0f32, 0.9),
+                sprite: Sprite {
+                    color: Color::rgba(0f32, 0f32, 0f32, 0.75),
+                    custom_size: Some(Vec2::new(SCREEN_WIDTH, SCREEN_HEIGHT)),
+                    ..default()
+                },
+                ..default()
+            },
+            StoreScreen,
+        ))
+        .with_children(|builder| {
+            builder.spawn(Text2dBundle {
+                text: Text::from_section(
+                    "STORE",
+                    TextStyle {
+                        font_size: 56f32,
+                        ..text_style.clone()
+                    },
+                ),
+                transform: Transform::from_xyz(0f32, 215f32, 0.1),
+                ..default()
+            });
+
+            builder
+                .spawn((
+                    Text2dBundle {
+                        text: Text::from_section(
+                            "",
+                            TextStyle {
+                                font_size: 32f32,
+                                ..text_style.clone()
+                            },
+                        ),
+                        transform: Transform::from_xyz(10f32, 172f32, 0.1),
+                        ..default()
+                    },
+                    StoreBalance,
+                ))
+                .with_children(|builder| {
+                    builder.spawn(SpriteBundle {
+                        texture: image_assets.coin.clone(),
+                        transform: Transform::from_xyz(-30f32, -2f32, 0f32),
+                        ..default()
+                    });
+                });
+
+            for category in Category::ALL {
+                let y = row_y(category.row());
+
+                builder.spawn((
+                    Text2dBundle {
+                        text: Text::from_section(
+                            category.label(),
+                            TextStyle {
+                                font_size: 28f32,
+                                ..text_style.clone()
+                            },
+                        ),
+                        transform: Transform::from_xyz(0f32, y + 38f32, 0.1),
+                        ..default()
+                    },
+                    category,
+                ));
+
+                builder.spawn((SpriteBundle::default(), StorePreview(category)));
+
+                for direction in [-1f32, 1f32] {
+                    builder.spawn(Text2dBundle {
+                        text: Text::from_section(
+                            ternary!(direction < 0f32, "<", ">"),
+                            text_style.clone(),
+                        ),
+                        transform: Transform::from_xyz(90f32 * direction, y, 0.1),
+                        ..default()
+                    });
+                }
+
+                builder.spawn((
+                    Text2dBundle {
+                        text: Text::from_section("", text_style.clone()),
+                        transform: Transform::from_xyz(0f32, y - 40f32, 0.1),
+                        ..default()
+                    },
+                    StoreCaption(category),
+                ));
+            }
+
+            builder.spawn(Text2dBundle {
+                text: Text::from_section(
+                    "ARROWS: BROWSE   ENTER: BUY/EQUIP   ESC: BACK",
+                    TextStyle {
+                        font_size: 18f32,
+                        ..text_style.clone()
+                    },
+                ),
+                transform: Transform::from_xyz(0f32, -SCREEN_HEIGHT.half() + 24f32, 0.1),
+                ..default()
+            });
+        })
+        .id();
+
+    spawn_labelled(
+        &mut commands,
+        SpriteButton {
+            color: Color::rgb(0.98, 0.6, 0.14),
+        },
+        Vec2::new(114f32, 28f32),
+        Transform::from_xyz(0f32, -200f32, 0.1),
+        Text::from_section("BACK", text_style),
+    )
+    .insert(BackButton)
+    .set_parent(screen);
+}
+
+fn navigate(
+    controls: Res<Controls>,
+    mut back: Back,
+    settings_return: Res<SettingsReturn>,
+    mut cursor: ResMut<StoreCursor>,
+    mut next_state: ResMut<NextState<GameState>>,
+) {
+    if back.pressed() || controls.open_store {
+        next_state.set(settings_return.0);
+    }
+
+    if controls.down {
+        cursor.row = (cursor.row + 1) % Category::ALL.len();
+    }
+
+    if controls.up {
+        cursor.row = (cursor.row + Category::ALL.len() - 1) % Category::ALL.len();
+    }
+
+    if controls.right {
+        cursor.browse(1);
+    }
+
+    if controls.left {
+        cursor.browse(-1);
+    }
+}
+
+fn purchase(
+    controls: Res<Controls>,
+    cursor: Res<StoreCursor>,
+    mut inventory: ResMut<Inventory>,
+    mut loadout: ResMut<Loadout>,
+    mut wallet: ResMut<Wallet>,
+    audio_assets: Res<AudioAssets>,
+    mut audio_event: EventWriter<AudioEvent>,
+) {
+    if !controls.confirm {
+        return;
+    }
+
+    let category = cursor.category();
+    let item = cursor.item(category);
+
+    match item {
+        Some(item) if !inventory.owns(item) => {
+            if wallet.balance < item.price() {
+                audio_event.send(AudioEvent::sfx(&audio_assets.hit));
+                return;
+            }
+
+            wallet.balance -= item.price();
+            inventory.0.insert(item);
+
+            audio_event.send(AudioEvent::sfx(&audio_assets.ding));
+        }
+        _ => {
+            audio_event.send(AudioEvent::sfx(&audio_assets.swoosh));
+        }
+    }
+
+    loadout.equip(category, item);
+}
+
+fn refresh_previews(
+    cursor: Res<StoreCursor>,
+    datetime: Res<DateTime>,
+    image_assets: Res<ImageAssets>,
+    mut previews: Query<(
+        &mut Sprite,
+        &mut Handle<Image>,
+        &mut Transform,
+        &StorePreview,
+    )>,
+) {
+    if !cursor.is_changed() {
+        return;
+    }
+
+    for (mut sprite, mut texture, mut transform, StorePreview(category)) in &mut previews {
+        let y = row_y(category.row());
+
+        // Preview what the default look would be right now.
+        let item = cursor.item(*category).unwrap_or(match category {
+            Category::Bird => Item::Bird(BirdColor::Yellow),
+            Category::Pipe => Item::Pipe(PipeColor::from(*datetime)),
+            Category::Background => Item::Background(Scenery::from(*datetime)),
+        });
+
+        (*sprite, *texture, *transform) = match item {
+            Item::Bird(color) => (
+                Sprite::default(),
+                color.frames(&image_assets)[1].clone(),
+                Transform::from_xyz(0f32, y, 0.1).with_scale(Vec3::splat(1.5)),
+            ),
+            Item::Pipe(color) => (
+                Sprite {
+                    rect: Some(Rect::new(0f32, 0f32, 52f32, 40f32)),
+                    ..default()
+                },
+                color.texture(&image_assets),
+                Transform::from_xyz(0f32, y, 0.1),
+            ),
+            Item::Background(scenery) => (
+                Sprite {
+                    rect: Some(Rect::new(0f32, 280f32, 288f32, 400f32)),
+                    custom_size: Some(Vec2::new(108f32, 45f32)),
+                    ..default()
+                },
+                scenery.texture(&image_assets),
+                Transform::from_xyz(0f32, y, 0.1),
+            ),
+        };
+    }
+}
+
+fn highlight_row(cursor: Res<StoreCursor>, mut labels: Query<(&mut Text, &Category)>) {
+    if !cursor.is_changed() {
+        return;
+    }
+
+    for (mut text, category) in &mut labels {
+        text.sections[0].style.color =
+            ternary!(*category == cursor.category(), Color::WHITE, Color::GRAY);
+    }
+}
+
+fn display_balance(wallet: Res<Wallet>, mut balance: Query<&mut Text, With<StoreBalance>>) {
+    if !wallet.is_changed() {
+        return;
+    }
+
+    for mut text in &mut balance {
+        text.sections[0].value = wallet.balance.to_string();
+    }
+}
+
+fn describe_items(
+    cursor: Res<StoreCursor>,
+    inventory: Res<Inventory>,
+    loadout: Res<Loadout>,
+    mut captions: Query<(&mut Text, &StoreCaption)>,
+) {
+    if !(cursor.is_changed() || inventory.is_changed() || loadout.is_changed()) {
+        return;
+    }
+
+    for (mut text, StoreCaption(category)) in &mut captions {
+        let item = cursor.item(*category);
+        let status = match item {
+            _ if loadout.equipped(*category) == item => "EQUIPPED".to_string(),
+            None => "FREE".to_string(),
+            Some(item) if inventory.owns(item) => "OWNED".to_string(),
+            Some(item) => format!("{} COINS", item.price()),
+        };
+        let name = item.map_or(category.default_name(), Item::name);
+
+        text.sections[0].value = format!("{name} - {status}");
+    }
+}
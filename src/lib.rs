@@ -1,7 +1,29 @@
 #![feature(decl_macro)]
 
+pub mod game;
+
 use bevy::prelude::*;
 
+pub const SCREEN_WIDTH: f32 = 288f32;
+pub const SCREEN_HEIGHT: f32 = 512f32;
+
+/// Represents the current state of the game.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Hash, States)]
+pub enum GameState {
+    #[default]
+    AssetLoading,
+    Menu,
+    Ready,
+    Store,
+    Gallery,
+    Leaderboard,
+    Controls,
+    Audio,
+    Playing,
+    Paused,
+    Over,
+}
+
 /// Add useful helper methods for Boolean type.
 pub trait Switcher {
     /// Change value to open state.
@@ -77,3 +99,10 @@ pub fn despawn<T: Component>(mut commands: Commands, entities: Query<Entity, Wit
         commands.entity(entity).despawn();
     }
 }
+
+/// Despawn entity that has specified components in the world along with all its children.
+pub fn despawn_recursive<T: Component>(mut commands: Commands, entities: Query<Entity, With<T>>) {
+    for entity in &entities {
+        commands.entity(entity).despawn_recursive();
+    }
+}
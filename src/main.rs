@@ -1,90 +1,79 @@
-#![feature(let_chains)]
+use bevy::{asset::AssetMetaCheck, prelude::*};
+use flappybust::{
+    game::{
+        collision::components::Obstacle, headless, replay::components::Replay, GamePlugin,
+        LaunchOptions,
+    },
+    GameState, SCREEN_HEIGHT, SCREEN_WIDTH,
+};
+use std::process;
 
-mod game;
-
-use bevy::{asset::AssetMetaCheck, prelude::*, window::close_on_esc};
-use game::{game_over::events::RestartButtonDisplayed, GamePlugin};
-
-const SCREEN_WIDTH: f32 = 288f32;
-const SCREEN_HEIGHT: f32 = 512f32;
+fn main() {
+    let options = LaunchOptions::from_env();
 
-/// Represents the current state of the game.
-#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Hash, States)]
-pub enum GameState {
-    #[default]
-    AssetLoading,
-    Ready,
-    Playing,
-    Over,
-}
+    if options.headless {
+        return run_headless(&options);
+    }
 
-fn main() {
     let mut app = App::new();
 
     app.init_state::<GameState>()
         .insert_resource(AssetMetaCheck::Never)
+        .insert_resource(options)
         .add_plugins((
-            DefaultPlugins.set(WindowPlugin {
-                primary_window: Some(Window {
-                    title: "Flappybust 🦀🦋".to_string(),
-                    resolution: (SCREEN_WIDTH, SCREEN_HEIGHT).into(),
+            DefaultPlugins
+                .set(WindowPlugin {
+                    primary_window: Some(Window {
+                        title: "Flappybust 🦀🦋".to_string(),
+                        resolution: (SCREEN_WIDTH, SCREEN_HEIGHT).into(),
+                        ..default()
+                    }),
                     ..default()
-                }),
-                ..default()
-            }),
+                })
+                .set(ImagePlugin::default_nearest()),
             GamePlugin,
         ))
         .add_systems(Startup, setup_camera)
-        .add_systems(
-            Update,
-            (
-                start_game.run_if(in_state(GameState::Ready)),
-                restart_game.run_if(in_state(GameState::Over)),
-                close_on_esc,
-            ),
-        )
         .run();
 }
 
-fn setup_camera(mut commands: Commands) {
-    commands.spawn(Camera2dBundle::default());
-}
+/// Play the given replay, the autopilot or a run without any flap,
+/// and print how it ended.
+fn run_headless(options: &LaunchOptions) {
+    let seed = options.seed.unwrap_or_else(rand::random);
 
-fn start_game(
-    key: Res<ButtonInput<KeyCode>>,
-    mouse: Res<ButtonInput<MouseButton>>,
-    mut next_state: ResMut<NextState<GameState>>,
-) {
-    if key.just_pressed(KeyCode::Space) || mouse.just_pressed(MouseButton::Left) {
-        next_state.set(GameState::Playing);
-    }
-}
+    let report = match &options.replay {
+        Some(path) => {
+            let replay = Replay::load(path).unwrap_or_else(|err| {
+                eprintln!("Failed to load replay {path}: {err}");
+                process::exit(1);
+            });
 
-fn restart_game(
-    key: Res<ButtonInput<KeyCode>>,
-    mouse: Res<ButtonInput<MouseButton>>,
-    mut next_state: ResMut<NextState<GameState>>,
-    mut cursor_moved: EventReader<CursorMoved>,
-    restart_btn_displayed: EventReader<RestartButtonDisplayed>,
-) {
-    if restart_btn_displayed.is_empty() {
-        return;
-    }
+            headless::simulate(replay, u64::MAX)
+        }
+        None if options.autopilot => headless::autopilot(
+            seed,
+            options.difficulty.unwrap_or_default(),
+            headless::MAX_TICKS,
+        ),
+        None => headless::simulate(
+            Replay {
+                seed,
+                preset: options.difficulty.unwrap_or_default(),
+                ..default()
+            },
+            u64::MAX,
+        ),
+    };
 
-    if key.just_pressed(KeyCode::Space) {
-        next_state.set(GameState::Ready);
-    }
+    println!(
+        "score: {}, death: {}, ticks: {}",
+        report.score,
+        report.death.map_or("none", Obstacle::name),
+        report.ticks
+    );
+}
 
-    if mouse.just_pressed(MouseButton::Left) {
-        for cursor in cursor_moved.read() {
-            // The cursor must be inside restart btn area.
-            if cursor.position.y > 273f32
-                && cursor.position.y < 310f32
-                && cursor.position.x > 90f32
-                && cursor.position.x < 200f32
-            {
-                next_state.set(GameState::Ready);
-            }
-        }
-    }
+fn setup_camera(mut commands: Commands) {
+    commands.spawn(Camera2dBundle::default());
 }
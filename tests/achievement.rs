@@ -0,0 +1,15 @@
+use flappybust::game::achievement::components::Achievements;
+use std::collections::HashSet;
+
+#[test]
+fn the_built_in_achievements_are_valid_and_have_unique_ids() {
+    let achievements = Achievements::default();
+    let ids: HashSet<&str> = achievements
+        .0
+        .iter()
+        .map(|achievement| achievement.id.as_str())
+        .collect();
+
+    assert!(!achievements.0.is_empty());
+    assert_eq!(ids.len(), achievements.0.len());
+}
@@ -0,0 +1,338 @@
+mod common;
+
+use bevy::{
+    audio::AudioSinkPlayback, ecs::system::RunSystemOnce, prelude::*, time::TimeUpdateStrategy,
+};
+use common::{advance_until, state};
+use flappybust::{
+    game::{
+        audio::{
+            components::{
+                AudioChannel, Mixer, MusicDirector, Slider, Sound, SoundId, SoundIds, Track,
+            },
+            events::{AudioControl, AudioEvent, Sounds},
+            AudioPlugin, MusicPlugin, SoundPlugin,
+        },
+        headless::HeadlessPlugin,
+        score::components::Score,
+    },
+    GameState,
+};
+use std::{
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Mutex,
+    },
+    time::Duration,
+};
+
+/// Stands in for the audio output, which the tests run without.
+#[derive(Component)]
+struct TestSink {
+    volume: Mutex<f32>,
+    speed: Mutex<f32>,
+    paused: AtomicBool,
+    stopped: AtomicBool,
+}
+
+impl AudioSinkPlayback for TestSink {
+    fn volume(&self) -> f32 {
+        *self.volume.lock().unwrap()
+    }
+
+    fn set_volume(&self, volume: f32) {
+        *self.volume.lock().unwrap() = volume;
+    }
+
+    fn speed(&self) -> f32 {
+        *self.speed.lock().unwrap()
+    }
+
+    fn set_speed(&self, speed: f32) {
+        *self.speed.lock().unwrap() = speed;
+    }
+
+    fn play(&self) {
+        self.paused.store(false, Ordering::Relaxed);
+    }
+
+    fn pause(&self) {
+        self.paused.store(true, Ordering::Relaxed);
+    }
+
+    fn is_paused(&self) -> bool {
+        self.paused.load(Ordering::Relaxed)
+    }
+
+    fn stop(&self) {
+        self.stopped.store(true, Ordering::Relaxed);
+    }
+
+    fn empty(&self) -> bool {
+        self.stopped.load(Ordering::Relaxed)
+    }
+}
+
+/// Give the new sounds a sink, as the audio output does once their source is loaded.
+fn start(mut commands: Commands, sounds: Query<(Entity, &PlaybackSettings), Added<Sound>>) {
+    for (entity, settings) in &sounds {
+        commands.entity(entity).insert(TestSink {
+            volume: Mutex::new(settings.volume.get()),
+            speed: Mutex::new(settings.speed),
+            paused: AtomicBool::new(false),
+            stopped: AtomicBool::new(false),
+        });
+    }
+}
+
+/// Sounds played without an audio device, each update lasting a tenth of a second.
+fn app() -> App {
+    let mut app = App::new();
+
+    app.add_plugins((MinimalPlugins, SoundPlugin::<TestSink>::default()))
+        .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+            100,
+        )))
+        .add_systems(PostUpdate, start);
+    app.update();
+
+    app
+}
+
+/// Play a sound through `Sounds`, the way the ones stopped later are.
+fn play(app: &mut App, audio_event: AudioEvent) -> SoundId {
+    let mut audio_event = Some(audio_event);
+    let id = app.world.run_system_once(move |mut sounds: Sounds| {
+        sounds.play(audio_event.take().expect("Sound must be played once."))
+    });
+
+    app.update();
+    id
+}
+
+fn playing(app: &mut App) -> Vec<SoundId> {
+    let mut ids: Vec<SoundId> = app
+        .world
+        .query::<&Sound>()
+        .iter(&app.world)
+        .map(|sound| sound.0)
+        .collect();
+    ids.sort();
+
+    ids
+}
+
+fn sink(app: &mut App, id: SoundId) -> &TestSink {
+    app.world
+        .query::<(&Sound, &TestSink)>()
+        .iter(&app.world)
+        .find(|(sound, _)| sound.0 == id)
+        .map(|(_, sink)| sink)
+        .unwrap()
+}
+
+#[test]
+fn channels_play_under_the_master_volume() {
+    let mut mixer = Mixer::default();
+
+    mixer.nudge(Slider::Master, -5f32);
+    mixer.nudge(Slider::Music, -2f32);
+
+    assert_eq!(mixer.volume(AudioChannel::Music), 0.5 * 0.8);
+    assert_eq!(mixer.volume(AudioChannel::Sfx), 0.5);
+
+    mixer.muted = true;
+
+    assert_eq!(mixer.volume(AudioChannel::Music), 0f32);
+    assert_eq!(mixer.volume(AudioChannel::Sfx), 0f32);
+}
+
+#[test]
+fn volumes_stay_between_silent_and_full() {
+    let mut mixer = Mixer::default();
+
+    mixer.nudge(Slider::Sfx, 3f32);
+    assert_eq!(mixer.level(Slider::Sfx), 1f32);
+
+    for _ in 0..12 {
+        mixer.nudge(Slider::Sfx, -1f32);
+    }
+    assert_eq!(mixer.level(Slider::Sfx), 0f32);
+
+    mixer.nudge(Slider::Sfx, 1f32);
+    assert_eq!(mixer.level(Slider::Sfx), 0.1);
+}
+
+#[test]
+fn old_save_files_keep_the_default_volumes() {
+    let mixer: Mixer = ron::from_str("(muted: true)").unwrap();
+
+    assert_eq!(
+        mixer,
+        Mixer {
+            muted: true,
+            ..Mixer::default()
+        }
+    );
+}
+
+#[test]
+fn newer_sounds_have_greater_ids() {
+    let mut ids = SoundIds::default();
+    let first = ids.next_id();
+
+    assert!(first < ids.next_id());
+}
+
+#[test]
+fn only_a_few_copies_of_an_effect_play_at_once() {
+    let mut app = app();
+    let wing = Handle::weak_from_u128(1);
+    let first = play(&mut app, AudioEvent::sfx(&wing));
+
+    for _ in 0..AudioPlugin::MAX_INSTANCES {
+        app.world.send_event(AudioEvent::sfx(&wing));
+    }
+
+    app.world
+        .send_event(AudioEvent::sfx(&Handle::weak_from_u128(2)));
+    app.update();
+
+    let ids = playing(&mut app);
+
+    assert_eq!(ids.len(), AudioPlugin::MAX_INSTANCES + 1);
+    assert!(!ids.contains(&first));
+}
+
+#[test]
+fn finished_sounds_are_despawned() {
+    let mut app = app();
+    let id = play(&mut app, AudioEvent::sfx(&Handle::weak_from_u128(1)));
+
+    app.update();
+    assert_eq!(playing(&mut app), [id]);
+
+    sink(&mut app, id).stop();
+    app.update();
+    assert!(playing(&mut app).is_empty());
+}
+
+#[test]
+fn a_sound_stopped_twice_is_despawned_once() {
+    let mut app = app();
+    let wing = Handle::weak_from_u128(1);
+    let id = play(&mut app, AudioEvent::sfx(&wing));
+
+    // The cap stops it too.
+    for _ in 0..AudioPlugin::MAX_INSTANCES {
+        app.world.send_event(AudioEvent::sfx(&wing));
+    }
+
+    app.world.send_event(AudioControl::Stop(id));
+    app.world.send_event(AudioControl::FadeOut(id, 0f32));
+    app.update();
+
+    let ids = playing(&mut app);
+
+    assert_eq!(ids.len(), AudioPlugin::MAX_INSTANCES);
+    assert!(!ids.contains(&id));
+}
+
+#[test]
+fn a_sound_fading_out_gets_quieter_then_stops() {
+    let mut app = app();
+    let id = play(&mut app, AudioEvent::music(&Handle::weak_from_u128(1)));
+
+    app.update();
+    assert_eq!(sink(&mut app, id).volume(), 1f32);
+
+    app.world.send_event(AudioControl::FadeOut(id, 1f32));
+
+    for _ in 0..5 {
+        app.update();
+    }
+
+    let volume = sink(&mut app, id).volume();
+    assert!(volume > 0f32 && volume < 1f32);
+
+    for _ in 0..10 {
+        app.update();
+    }
+
+    assert!(playing(&mut app).is_empty());
+}
+
+#[test]
+fn the_gameplay_layer_rises_with_the_score() {
+    assert_eq!(Track::layer_volume(9), 0f32);
+    assert!(Track::layer_volume(10) > Track::layer_volume(9));
+    assert_eq!(Track::layer_volume(1000), Track::LAYER_VOLUME);
+}
+
+#[test]
+fn a_sound_fading_in_falls_from_where_it_got_to() {
+    let mut app = app();
+    let id = play(
+        &mut app,
+        AudioEvent::music(&Handle::weak_from_u128(1)).fading_in(1f32),
+    );
+
+    for _ in 0..3 {
+        app.update();
+    }
+
+    let risen = sink(&mut app, id).volume();
+    assert!(risen > 0f32 && risen < 0.5);
+
+    app.world.send_event(AudioControl::FadeOut(id, 1f32));
+    app.update();
+
+    assert!(sink(&mut app, id).volume() < risen);
+}
+
+#[test]
+fn the_music_follows_the_state_and_the_layer_the_score() {
+    let mut app = App::new();
+
+    app.add_plugins((
+        HeadlessPlugin,
+        SoundPlugin::<TestSink>::default(),
+        MusicPlugin,
+    ))
+    .add_systems(PostUpdate, start);
+    app.finish();
+    app.cleanup();
+
+    advance_until(&mut app, |app| state(app) == GameState::Ready);
+    app.update();
+
+    let director = app.world.resource::<MusicDirector>();
+    assert_eq!(director.track(), Some(Track::Menu));
+    assert_eq!(director.layer(), None);
+    assert_eq!(playing(&mut app).len(), 1);
+
+    app.world
+        .resource_mut::<NextState<GameState>>()
+        .set(GameState::Playing);
+    app.update();
+    app.update();
+
+    let director = app.world.resource::<MusicDirector>();
+    let layer = director.layer().unwrap();
+    assert_eq!(director.track(), Some(Track::Gameplay));
+    // The menu loop fades out under the theme and the layer.
+    assert_eq!(playing(&mut app).len(), 3);
+    assert_eq!(sink(&mut app, layer).volume(), 0f32);
+
+    app.world.resource_mut::<Score>().current = 40;
+    app.update();
+    assert!(sink(&mut app, layer).volume() > 0f32);
+
+    // The bird falls to the ground on its own.
+    advance_until(&mut app, |app| state(app) == GameState::Over);
+    app.update();
+
+    let director = app.world.resource::<MusicDirector>();
+    assert_eq!(director.track(), Some(Track::Over));
+    assert_eq!(director.layer(), None);
+}
@@ -0,0 +1,9 @@
+use flappybust::game::{difficulty::components::Preset, headless};
+
+#[test]
+fn autopilot_flies_past_pipes() {
+    let report = headless::autopilot(2, Preset::Normal, 3000);
+
+    assert_eq!(report.death, None);
+    assert!(report.score >= 10);
+}
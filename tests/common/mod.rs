@@ -0,0 +1,115 @@
+//! Helpers driving a headless game, shared by the tests.
+
+// Each test file only uses some of them.
+#![allow(dead_code)]
+
+use bevy::{
+    input::{
+        gamepad::{
+            GamepadButtonChangedEvent, GamepadConnection, GamepadConnectionEvent, GamepadEvent,
+            GamepadInfo,
+        },
+        keyboard::{Key, KeyboardInput},
+        touch::TouchPhase,
+        ButtonState,
+    },
+    prelude::*,
+};
+use flappybust::{game::headless::HeadlessPlugin, GameState};
+
+/// Frames after which a test gives up waiting.
+pub const PATIENCE: usize = 2000;
+
+/// Gamepad the tests press buttons on.
+const GAMEPAD: Gamepad = Gamepad { id: 0 };
+
+/// Headless game, on the ready screen once its assets have loaded.
+pub fn app() -> App {
+    let mut app = App::new();
+
+    app.add_plugins(HeadlessPlugin);
+    app.finish();
+    app.cleanup();
+
+    advance_until(&mut app, |app| state(app) == GameState::Ready);
+
+    app
+}
+
+pub fn state(app: &App) -> GameState {
+    *app.world.resource::<State<GameState>>().get()
+}
+
+pub fn advance_until(app: &mut App, mut done: impl FnMut(&mut App) -> bool) {
+    for _ in 0..PATIENCE {
+        if done(app) {
+            return;
+        }
+
+        app.update();
+    }
+
+    panic!("Gave up after {PATIENCE} frames in {:?}", state(app));
+}
+
+fn send_key(app: &mut App, key_code: KeyCode, logical_key: Key, state: ButtonState) {
+    app.world.send_event(KeyboardInput {
+        key_code,
+        logical_key,
+        state,
+        window: Entity::PLACEHOLDER,
+    });
+}
+
+/// Press and release a key over two frames, then let the state change.
+pub fn tap(app: &mut App, key_code: KeyCode, logical_key: Key) {
+    send_key(app, key_code, logical_key.clone(), ButtonState::Pressed);
+    app.update();
+    send_key(app, key_code, logical_key, ButtonState::Released);
+    app.update();
+    app.update();
+}
+
+/// Touch the screen at a window position and lift the finger over two frames,
+/// then let the state change.
+pub fn touch(app: &mut App, position: Vec2) {
+    for phase in [TouchPhase::Started, TouchPhase::Ended] {
+        app.world.send_event(TouchInput {
+            phase,
+            position,
+            window: Entity::PLACEHOLDER,
+            force: None,
+            id: 0,
+        });
+        app.update();
+    }
+
+    app.update();
+}
+
+/// Press and release a button of a gamepad over two frames, then let the state change.
+/// The gamepad gets connected on its first press.
+pub fn press(app: &mut App, button_type: GamepadButtonType) {
+    if !app.world.resource::<Gamepads>().contains(GAMEPAD) {
+        app.world
+            .send_event(GamepadEvent::Connection(GamepadConnectionEvent::new(
+                GAMEPAD,
+                GamepadConnection::Connected(GamepadInfo {
+                    name: "Test gamepad".to_string(),
+                }),
+            )));
+        app.update();
+    }
+
+    for value in [1f32, 0f32] {
+        app.world
+            .send_event(GamepadEvent::Button(GamepadButtonChangedEvent::new(
+                GAMEPAD,
+                button_type,
+                value,
+            )));
+        app.update();
+    }
+
+    app.update();
+}
@@ -0,0 +1,36 @@
+use flappybust::game::{config::components::GameConfig, difficulty::components::Preset};
+
+#[test]
+fn the_built_in_config_is_valid() {
+    assert_eq!(GameConfig::default().validate(), Ok(()));
+}
+
+#[test]
+fn backwards_ranges_are_rejected() {
+    let mut config = GameConfig::default();
+    config.bird.lowest_rotation = config.bird.highest_rotation + 1f32;
+    assert!(config.validate().is_err());
+
+    let mut config = GameConfig::default();
+    config.game_over.scoreboard_start = config.game_over.scoreboard_end + 1f32;
+    assert!(config.validate().is_err());
+
+    let mut config = GameConfig::default();
+    let curve = config.difficulty.get_mut(&Preset::Hard).unwrap();
+    curve.lowest = ron::from_str("[(0, -100.0), (50, 0.0)]").unwrap();
+    curve.highest = ron::from_str("[(0, -50.0)]").unwrap();
+    assert!(config.validate().is_err());
+}
+
+#[test]
+fn pipes_need_a_gap_and_some_spacing() {
+    let mut config = GameConfig::default();
+    let curve = config.difficulty.get_mut(&Preset::Easy).unwrap();
+    curve.gap = ron::from_str("[(0, 90.0), (40, 0.0)]").unwrap();
+    assert!(config.validate().is_err());
+
+    let mut config = GameConfig::default();
+    let curve = config.difficulty.get_mut(&Preset::Easy).unwrap();
+    curve.spacing = ron::from_str("[(30, 150.0), (10, 180.0)]").unwrap();
+    assert!(config.validate().is_err());
+}
@@ -0,0 +1,160 @@
+mod common;
+
+use bevy::{app::AppExit, input::keyboard::Key, prelude::*};
+use common::{advance_until, app, press, state, tap};
+use flappybust::{
+    game::controls::components::{Action, Binding, Bindings},
+    GameState,
+};
+
+#[test]
+fn binding_a_key_twice_unbinds_it() {
+    let mut bindings = Bindings::default();
+    let key = Binding::Key(KeyCode::KeyF);
+
+    bindings.toggle(Action::Flap, key);
+    assert!(bindings.get(Action::Flap).contains(&key));
+
+    bindings.toggle(Action::Flap, key);
+    assert_eq!(bindings, Bindings::default());
+}
+
+#[test]
+fn bindings_survive_a_save_file() {
+    let mut bindings = Bindings::default();
+    bindings.toggle(Action::Quit, Binding::Gamepad(GamepadButtonType::Mode));
+    bindings.toggle(Action::Mute, Binding::Key(KeyCode::KeyM));
+
+    let contents = ron::to_string(&bindings).unwrap();
+
+    assert_eq!(ron::from_str::<Bindings>(&contents).unwrap(), bindings);
+}
+
+#[test]
+fn flapping_follows_keys_rebound_on_the_controls_screen() {
+    let mut app = app();
+    let f = || Key::Character("f".into());
+
+    tap(&mut app, KeyCode::KeyC, Key::Character("c".into()));
+    assert_eq!(state(&app), GameState::Controls);
+
+    // Flap is the first row: bind F, then unbind space.
+    tap(&mut app, KeyCode::Enter, Key::Enter);
+    tap(&mut app, KeyCode::KeyF, f());
+    tap(&mut app, KeyCode::Enter, Key::Enter);
+    tap(&mut app, KeyCode::Space, Key::Space);
+    tap(&mut app, KeyCode::Escape, Key::Escape);
+    assert_eq!(state(&app), GameState::Ready);
+
+    let flap = app.world.resource::<Bindings>().get(Action::Flap);
+    assert!(flap.contains(&Binding::Key(KeyCode::KeyF)));
+    assert!(!flap.contains(&Binding::Key(KeyCode::Space)));
+
+    tap(&mut app, KeyCode::Space, Key::Space);
+    assert_eq!(state(&app), GameState::Ready);
+
+    tap(&mut app, KeyCode::KeyF, f());
+    assert_eq!(state(&app), GameState::Playing);
+}
+
+#[test]
+fn escape_cancels_a_rebinding_and_the_screen_follows_rebound_keys() {
+    let mut app = app();
+
+    tap(&mut app, KeyCode::KeyC, Key::Character("c".into()));
+    tap(&mut app, KeyCode::Enter, Key::Enter);
+    tap(&mut app, KeyCode::Escape, Key::Escape);
+    assert_eq!(state(&app), GameState::Controls);
+    assert_eq!(*app.world.resource::<Bindings>(), Bindings::default());
+
+    // Unbind C from the controls row, the sixth one down.
+    for _ in 0..6 {
+        tap(&mut app, KeyCode::ArrowDown, Key::ArrowDown);
+    }
+
+    tap(&mut app, KeyCode::Enter, Key::Enter);
+    tap(&mut app, KeyCode::KeyC, Key::Character("c".into()));
+    tap(&mut app, KeyCode::KeyC, Key::Character("c".into()));
+    assert_eq!(state(&app), GameState::Controls);
+
+    tap(&mut app, KeyCode::Escape, Key::Escape);
+    assert_eq!(state(&app), GameState::Ready);
+
+    tap(&mut app, KeyCode::KeyC, Key::Character("c".into()));
+    assert_eq!(state(&app), GameState::Ready);
+}
+
+#[test]
+fn the_back_binding_cancels_a_rebinding() {
+    let mut app = app();
+
+    let mut bindings = app.world.resource_mut::<Bindings>();
+    bindings.toggle(Action::Back, Binding::Key(KeyCode::Escape));
+    bindings.toggle(Action::Back, Binding::Key(KeyCode::Backspace));
+    let rebound = bindings.clone();
+
+    // Flap is the first row.
+    tap(&mut app, KeyCode::KeyC, Key::Character("c".into()));
+    tap(&mut app, KeyCode::Enter, Key::Enter);
+    tap(&mut app, KeyCode::Backspace, Key::Backspace);
+    assert_eq!(*app.world.resource::<Bindings>(), rebound);
+
+    tap(&mut app, KeyCode::Enter, Key::Enter);
+    tap(&mut app, KeyCode::Escape, Key::Escape);
+    let flap = app.world.resource::<Bindings>().get(Action::Flap);
+    assert!(flap.contains(&Binding::Key(KeyCode::Escape)));
+}
+
+#[test]
+fn quitting_waits_for_the_run_to_end() {
+    #[derive(Resource, Default)]
+    struct Exited(bool);
+
+    let mut app = app();
+    app.init_resource::<Exited>().add_systems(
+        Last,
+        |mut app_exit: EventReader<AppExit>, mut exited: ResMut<Exited>| {
+            exited.0 |= app_exit.read().count() > 0;
+        },
+    );
+    let quit = |app: &App| app.world.resource::<Exited>().0;
+
+    tap(&mut app, KeyCode::Space, Key::Space);
+    tap(&mut app, KeyCode::KeyQ, Key::Character("q".into()));
+    assert_eq!(state(&app), GameState::Playing);
+    assert!(!quit(&app));
+
+    advance_until(&mut app, |app| state(app) == GameState::Over);
+    tap(&mut app, KeyCode::KeyQ, Key::Character("q".into()));
+    assert!(quit(&app));
+}
+
+#[test]
+fn the_store_opens_and_closes_with_a_gamepad_bound_to_it() {
+    let mut app = app();
+
+    app.world
+        .resource_mut::<Bindings>()
+        .toggle(Action::Store, Binding::Gamepad(GamepadButtonType::North));
+
+    press(&mut app, GamepadButtonType::North);
+    assert_eq!(state(&app), GameState::Store);
+
+    press(&mut app, GamepadButtonType::East);
+    assert_eq!(state(&app), GameState::Ready);
+}
+
+#[test]
+fn actions_added_since_a_save_get_their_default_bindings() {
+    let mut bindings = Bindings::default();
+    bindings.0.remove(&Action::Back);
+    bindings.toggle(Action::Audio, Binding::Key(KeyCode::KeyV));
+
+    let completed = bindings.complete();
+
+    assert_eq!(
+        completed.get(Action::Back),
+        Bindings::default().get(Action::Back)
+    );
+    assert!(completed.get(Action::Audio).is_empty());
+}
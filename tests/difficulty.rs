@@ -0,0 +1,25 @@
+use flappybust::game::difficulty::components::{Preset, Presets};
+
+#[test]
+fn pipes_get_tighter_and_faster_as_the_score_grows() {
+    let curve = Presets::default().get(Preset::Normal).clone();
+    let (start, later) = (curve.at(0), curve.at(40));
+
+    assert!(later.gap < start.gap);
+    assert!(later.spacing < start.spacing);
+    assert!(later.speed > start.speed);
+    assert!(later.lowest < start.lowest && later.highest > start.highest);
+    assert_eq!(curve.at(1000), curve.at(10_000));
+}
+
+#[test]
+fn harder_presets_start_harder() {
+    let presets = Presets::default();
+    let [easy, normal, hard] =
+        [Preset::Easy, Preset::Normal, Preset::Hard].map(|preset| presets.get(preset).at(0));
+
+    assert!(easy.gap > normal.gap && normal.gap > hard.gap);
+    assert!(easy.speed < normal.speed && normal.speed < hard.speed);
+    assert!(easy.gravity < normal.gravity && normal.gravity < hard.gravity);
+    assert_eq!(presets.get(Preset::Custom).at(0), normal);
+}
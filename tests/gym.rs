@@ -0,0 +1,52 @@
+use flappybust::game::gym::{components::Step, Gym};
+
+/// Steps after which a run is cut short.
+const PATIENCE: usize = 2000;
+
+fn run(gym: &mut Gym, seed: u64, flap: impl Fn(usize) -> bool) -> Vec<Step> {
+    gym.reset(Some(seed));
+
+    (0..PATIENCE)
+        .map(|tick| gym.step(flap(tick)))
+        .scan(false, |done, step| {
+            let was_done = *done;
+            *done = step.done;
+
+            (!was_done).then_some(step)
+        })
+        .collect()
+}
+
+#[test]
+fn a_run_without_flaps_ends_on_the_ground() {
+    let mut gym = Gym::new();
+    let steps = run(&mut gym, 1, |_| false);
+    let last = steps.last().unwrap();
+
+    assert!(last.done);
+    assert!(steps[..steps.len() - 1]
+        .iter()
+        .all(|step| !step.done && step.reward == Gym::SURVIVAL_REWARD));
+    assert!(gym.step(true).done);
+}
+
+#[test]
+fn the_same_seed_and_actions_give_the_same_run() {
+    let mut gym = Gym::new();
+    let flap = |tick| tick % 24 == 0;
+
+    let first = run(&mut gym, 7, flap);
+    let second = run(&mut gym, 7, flap);
+
+    assert!(first.iter().any(|step| step.reward >= Gym::PIPE_REWARD));
+    assert_eq!(first, second);
+}
+
+#[test]
+fn the_next_gap_is_ahead_of_the_bird() {
+    let mut gym = Gym::new();
+    let observation = gym.reset(Some(3));
+
+    assert!(observation.gap_distance > 0f32);
+    assert!(observation.gap_top > observation.gap_bottom);
+}
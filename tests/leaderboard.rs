@@ -0,0 +1,64 @@
+use flappybust::game::{
+    bird::components::BirdColor,
+    difficulty::components::Preset,
+    leaderboard::components::{Entry, Leaderboard, NameEntry},
+    DateTime,
+};
+
+fn entry(name: &str, score: usize, preset: Preset) -> Entry {
+    Entry {
+        name: name.to_string(),
+        score,
+        date: "2024-05-01".to_string(),
+        bird: BirdColor::Yellow,
+        datetime: DateTime::Day,
+        medal: None,
+        preset,
+    }
+}
+
+#[test]
+fn each_difficulty_keeps_its_best_runs() {
+    let mut leaderboard = Leaderboard::default();
+
+    for score in 1..=Leaderboard::SIZE + 2 {
+        leaderboard.insert(entry("AMY", score, Preset::Normal));
+    }
+    leaderboard.insert(entry("BOB", 3, Preset::Hard));
+
+    assert_eq!(leaderboard.table(Preset::Normal).count(), Leaderboard::SIZE);
+    assert_eq!(leaderboard.best(Preset::Normal), Leaderboard::SIZE + 2);
+    assert_eq!(leaderboard.best(Preset::Hard), 3);
+    assert_eq!(leaderboard.best_overall(), Leaderboard::SIZE + 2);
+    assert!(!leaderboard.qualifies(Preset::Normal, 3));
+    assert!(leaderboard.qualifies(Preset::Hard, 1));
+    assert!(!leaderboard.qualifies(Preset::Easy, 0));
+}
+
+#[test]
+fn ties_rank_below_older_runs() {
+    let mut leaderboard = Leaderboard::default();
+
+    leaderboard.insert(entry("AMY", 5, Preset::Normal));
+    assert_eq!(leaderboard.rank(Preset::Normal, 5), 1);
+
+    leaderboard.insert(entry("BOB", 5, Preset::Normal));
+    let names: Vec<_> = leaderboard
+        .table(Preset::Normal)
+        .map(|entry| entry.name.as_str())
+        .collect();
+
+    assert_eq!(names, ["AMY", "BOB"]);
+}
+
+#[test]
+fn typed_names_are_short_and_readable() {
+    let mut entry = NameEntry::default();
+
+    entry.push_str("  ");
+    assert_eq!(entry.name(), NameEntry::DEFAULT);
+
+    entry.push_str("zoë-the-bird and friends");
+    assert_eq!(entry.0.chars().count(), NameEntry::MAX_LENGTH);
+    assert_eq!(entry.name(), "ZOTHEBIRD");
+}
@@ -0,0 +1,156 @@
+mod common;
+
+use bevy::{input::keyboard::Key, prelude::*};
+use common::{app, press, state, tap, touch};
+use flappybust::{
+    game::{
+        difficulty::components::Preset,
+        menu::components::{MenuPage, SettingsReturn},
+    },
+    GameState,
+};
+
+#[test]
+fn escape_opens_the_menu_and_play_leaves_it() {
+    let mut app = app();
+
+    tap(&mut app, KeyCode::Escape, Key::Escape);
+    assert_eq!(state(&app), GameState::Menu);
+
+    tap(&mut app, KeyCode::Enter, Key::Enter);
+    assert_eq!(state(&app), GameState::Ready);
+}
+
+#[test]
+fn settings_screens_go_back_to_the_menu() {
+    let mut app = app();
+
+    tap(&mut app, KeyCode::Escape, Key::Escape);
+    tap(&mut app, KeyCode::ArrowDown, Key::ArrowDown);
+    tap(&mut app, KeyCode::ArrowDown, Key::ArrowDown);
+    tap(&mut app, KeyCode::Enter, Key::Enter);
+    assert_eq!(*app.world.resource::<MenuPage>(), MenuPage::Settings);
+
+    // Settings is the third item of the main page, controls the second of its own.
+    tap(&mut app, KeyCode::ArrowDown, Key::ArrowDown);
+    tap(&mut app, KeyCode::Enter, Key::Enter);
+    assert_eq!(state(&app), GameState::Controls);
+
+    tap(&mut app, KeyCode::Escape, Key::Escape);
+    assert_eq!(state(&app), GameState::Menu);
+    assert_eq!(*app.world.resource::<MenuPage>(), MenuPage::Settings);
+
+    tap(&mut app, KeyCode::Escape, Key::Escape);
+    assert_eq!(*app.world.resource::<MenuPage>(), MenuPage::Main);
+}
+
+#[test]
+fn the_difficulty_item_goes_through_the_presets() {
+    let mut app = app();
+    let preset = *app.world.resource::<Preset>();
+
+    tap(&mut app, KeyCode::Escape, Key::Escape);
+    tap(&mut app, KeyCode::ArrowDown, Key::ArrowDown);
+    tap(&mut app, KeyCode::ArrowDown, Key::ArrowDown);
+    tap(&mut app, KeyCode::Enter, Key::Enter);
+
+    // Wrapping up from the top reaches back, then difficulty.
+    tap(&mut app, KeyCode::ArrowUp, Key::ArrowUp);
+    tap(&mut app, KeyCode::ArrowUp, Key::ArrowUp);
+    tap(&mut app, KeyCode::Enter, Key::Enter);
+
+    assert_eq!(*app.world.resource::<Preset>(), preset.next());
+}
+
+#[test]
+fn a_gamepad_alone_goes_through_the_menu_and_the_controls_screen() {
+    let mut app = app();
+
+    press(&mut app, GamepadButtonType::East);
+    assert_eq!(state(&app), GameState::Menu);
+
+    press(&mut app, GamepadButtonType::DPadDown);
+    press(&mut app, GamepadButtonType::DPadDown);
+    press(&mut app, GamepadButtonType::South);
+    press(&mut app, GamepadButtonType::DPadDown);
+    press(&mut app, GamepadButtonType::South);
+    assert_eq!(state(&app), GameState::Controls);
+
+    press(&mut app, GamepadButtonType::East);
+    assert_eq!(state(&app), GameState::Menu);
+    assert_eq!(*app.world.resource::<MenuPage>(), MenuPage::Settings);
+
+    press(&mut app, GamepadButtonType::East);
+    press(&mut app, GamepadButtonType::South);
+    assert_eq!(state(&app), GameState::Ready);
+}
+
+#[test]
+fn touches_open_the_menu_and_leave_the_controls_screen() {
+    let mut app = app();
+
+    // The menu button, at the bottom of the ready screen.
+    touch(&mut app, Vec2::new(144f32, 442f32));
+    assert_eq!(state(&app), GameState::Menu);
+
+    // The menu items are Bevy UI, which the headless game leaves out.
+    app.world.insert_resource(SettingsReturn(GameState::Menu));
+    app.world
+        .resource_mut::<NextState<GameState>>()
+        .set(GameState::Controls);
+    app.update();
+    app.update();
+
+    // Away from the back button.
+    touch(&mut app, Vec2::new(144f32, 100f32));
+    assert_eq!(state(&app), GameState::Controls);
+
+    touch(&mut app, Vec2::new(144f32, 456f32));
+    assert_eq!(state(&app), GameState::Menu);
+}
+
+#[test]
+fn a_gamepad_reaches_the_store_and_the_leaderboard_from_the_menu() {
+    let mut app = app();
+
+    // The store is the second item of the main page.
+    press(&mut app, GamepadButtonType::East);
+    press(&mut app, GamepadButtonType::DPadDown);
+    press(&mut app, GamepadButtonType::South);
+    assert_eq!(state(&app), GameState::Store);
+
+    press(&mut app, GamepadButtonType::East);
+    assert_eq!(state(&app), GameState::Menu);
+
+    // The leaderboard is the first item of the stats page, right below settings.
+    press(&mut app, GamepadButtonType::DPadDown);
+    press(&mut app, GamepadButtonType::DPadDown);
+    press(&mut app, GamepadButtonType::South);
+    assert_eq!(*app.world.resource::<MenuPage>(), MenuPage::Stats);
+
+    press(&mut app, GamepadButtonType::South);
+    assert_eq!(state(&app), GameState::Leaderboard);
+
+    press(&mut app, GamepadButtonType::East);
+    assert_eq!(state(&app), GameState::Menu);
+    assert_eq!(*app.world.resource::<MenuPage>(), MenuPage::Stats);
+}
+
+#[test]
+fn the_back_button_leaves_the_gallery_for_the_menu() {
+    let mut app = app();
+
+    touch(&mut app, Vec2::new(144f32, 442f32));
+    assert_eq!(state(&app), GameState::Menu);
+
+    // The menu items are Bevy UI, which the headless game leaves out.
+    app.world.insert_resource(SettingsReturn(GameState::Menu));
+    app.world
+        .resource_mut::<NextState<GameState>>()
+        .set(GameState::Gallery);
+    app.update();
+    app.update();
+
+    touch(&mut app, Vec2::new(144f32, 456f32));
+    assert_eq!(state(&app), GameState::Menu);
+}
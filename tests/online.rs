@@ -0,0 +1,126 @@
+use flappybust::game::{
+    bird::components::BirdColor,
+    config::components::GameConfig,
+    difficulty::components::Preset,
+    gym::Gym,
+    headless,
+    leaderboard::components::{Entry, Leaderboard},
+    online::{components::Submission, server},
+    replay::components::Replay,
+    DateTime,
+};
+use std::sync::Mutex;
+
+/// Steps after which a run is cut short.
+const PATIENCE: usize = 2000;
+
+/// Fly through a few gaps with a simple rule and return the replay.
+fn play(seed: u64) -> (Replay, usize) {
+    let mut gym = Gym::new();
+    let mut observation = gym.reset(Some(seed));
+    let mut score = 0;
+
+    for _ in 0..PATIENCE {
+        let step = gym.step(observation.gap_bottom > -12f32 && observation.velocity > 0f32);
+
+        observation = step.observation;
+        score += (step.reward >= Gym::PIPE_REWARD) as usize;
+
+        if step.done {
+            break;
+        }
+    }
+
+    (gym.replay(), score)
+}
+
+fn submit(replay: Replay, score: usize, leaderboard: &Mutex<Leaderboard>) -> (u16, String) {
+    submit_with(replay, score, GameConfig::default().digest(), leaderboard)
+}
+
+fn submit_with(
+    replay: Replay,
+    score: usize,
+    config: u64,
+    leaderboard: &Mutex<Leaderboard>,
+) -> (u16, String) {
+    let submission = Submission {
+        entry: Entry {
+            name: "amy".to_string(),
+            score,
+            date: "2024-05-01".to_string(),
+            bird: BirdColor::Blue,
+            datetime: DateTime::Night,
+            medal: None,
+            preset: replay.preset,
+        },
+        replay,
+        config,
+    };
+
+    server::respond(
+        "POST",
+        "/runs",
+        &ron::to_string(&submission).unwrap(),
+        leaderboard,
+    )
+}
+
+#[test]
+fn an_honest_run_is_ranked() {
+    let leaderboard = Mutex::new(Leaderboard::default());
+    let (replay, score) = play(5);
+
+    assert!(score > 0);
+    assert_eq!(submit(replay, score, &leaderboard), (201, "1".to_string()));
+    let ranked = leaderboard.lock().unwrap().entries[0].clone();
+
+    assert_eq!(leaderboard.lock().unwrap().best(Preset::Normal), score);
+    assert_eq!(ranked.name, "AMY");
+
+    let (status, body) = server::respond("GET", "/leaderboard", "", &leaderboard);
+    let entries: Vec<Entry> = ron::from_str(&body).unwrap();
+
+    assert_eq!(status, 200);
+    assert_eq!(entries.len(), 1);
+}
+
+#[test]
+fn a_run_claiming_more_than_it_scores_is_rejected() {
+    let leaderboard = Mutex::new(Leaderboard::default());
+    let (replay, score) = play(5);
+
+    assert_eq!(submit(replay, score + 1, &leaderboard).0, 422);
+    assert!(leaderboard.lock().unwrap().entries.is_empty());
+    assert_eq!(server::respond("POST", "/runs", "(", &leaderboard).0, 400);
+}
+
+#[test]
+fn the_table_shows_the_bird_and_time_of_day_of_the_seed() {
+    let leaderboard = Mutex::new(Leaderboard::default());
+    let (replay, score) = play(5);
+    let report = headless::simulate(replay.clone(), replay.flaps.last().unwrap() + 600);
+
+    submit(replay, score, &leaderboard);
+
+    let ranked = leaderboard.lock().unwrap().entries[0].clone();
+
+    assert_eq!(Some(ranked.bird), report.bird);
+    assert_eq!(ranked.datetime, report.datetime);
+}
+
+#[test]
+fn runs_played_with_another_config_or_for_too_long_are_rejected() {
+    let leaderboard = Mutex::new(Leaderboard::default());
+    let (replay, score) = play(5);
+
+    assert_eq!(submit_with(replay.clone(), score, 1, &leaderboard).0, 422);
+
+    let stalled = Replay {
+        start: u64::MAX,
+        ..replay
+    };
+
+    assert_eq!(submit(stalled, score, &leaderboard).0, 422);
+    assert!(leaderboard.lock().unwrap().entries.is_empty());
+}
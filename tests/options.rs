@@ -0,0 +1,33 @@
+use flappybust::game::LaunchOptions;
+
+fn options(pairs: &[(&str, &str)]) -> LaunchOptions {
+    LaunchOptions::from_lookup(|name| {
+        pairs
+            .iter()
+            .find(|(key, _)| *key == name)
+            .map(|(_, value)| value.to_string())
+    })
+}
+
+#[test]
+fn flags_are_on_when_given_without_a_value_or_a_true_one() {
+    let options = options(&[("headless", ""), ("autopilot", "1")]);
+
+    assert!(options.headless);
+    assert!(options.autopilot);
+}
+
+#[test]
+fn flags_set_to_zero_or_false_stay_off() {
+    for value in ["0", "false", "FALSE", "no", "off"] {
+        let options = options(&[("headless", value), ("autopilot", value)]);
+
+        assert!(!options.headless, "headless={value}");
+        assert!(!options.autopilot, "autopilot={value}");
+    }
+
+    let options = options(&[]);
+
+    assert!(!options.headless);
+    assert!(!options.autopilot);
+}
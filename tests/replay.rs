@@ -0,0 +1,39 @@
+mod common;
+
+use bevy::prelude::*;
+use common::{advance_until, state};
+use flappybust::{
+    game::{
+        audio::components::Mixer,
+        headless::HeadlessPlugin,
+        replay::components::{Playback, Replay},
+        save::{components::Statistics, SavePlugin},
+    },
+    GameState,
+};
+
+#[test]
+fn playing_a_replay_leaves_the_statistics_alone() {
+    let mut app = App::new();
+
+    // The mixer is part of the save, but the headless game plays no sound.
+    app.add_plugins((HeadlessPlugin, SavePlugin))
+        .init_resource::<Mixer>();
+    app.finish();
+    app.cleanup();
+
+    advance_until(&mut app, |app| state(app) == GameState::Ready);
+
+    let statistics = app.world.resource::<Statistics>().clone();
+
+    app.world.insert_resource(Playback(Replay {
+        seed: 7,
+        flaps: vec![0, 20, 40, 60],
+        ..Default::default()
+    }));
+
+    advance_until(&mut app, |app| state(app) == GameState::Over);
+    app.update();
+
+    assert_eq!(*app.world.resource::<Statistics>(), statistics);
+}
@@ -0,0 +1,288 @@
+mod common;
+
+use bevy::{ecs::event::Events, input::keyboard::Key, prelude::*};
+use common::{advance_until, app, press, state, tap, touch};
+use flappybust::{
+    game::{
+        achievement::components::Unlocked,
+        autopilot::components::Autopilot,
+        bird::{components::Bird, events::DeathEvent},
+        collision::{components::Obstacle, events::CollisionEvent},
+        difficulty::components::Preset,
+        game_over::components::{GameOverText, Medal, MenuButton, RestartButton, Scoreboard},
+        interpolation::components::Interpolated,
+        leaderboard::components::{Leaderboard, NameEntry},
+        pipe::components::Pipe,
+        score::components::Score,
+    },
+    GameState, SCREEN_HEIGHT,
+};
+
+fn tap_space(app: &mut App) {
+    tap(app, KeyCode::Space, Key::Space);
+}
+
+fn count<T: Component>(app: &mut App) -> usize {
+    app.world
+        .query_filtered::<(), With<T>>()
+        .iter(&app.world)
+        .count()
+}
+
+/// Number of events still readable that match `filter`.
+fn sent<E: Event>(app: &App, filter: impl Fn(&E) -> bool) -> usize {
+    let events = app.world.resource::<Events<E>>();
+
+    events
+        .get_reader()
+        .read(events)
+        .filter(|event| filter(event))
+        .count()
+}
+
+fn restart_button_shown(app: &mut App) -> bool {
+    app.world
+        .query_filtered::<&Visibility, With<RestartButton>>()
+        .iter(&app.world)
+        .any(|visibility| *visibility == Visibility::Visible)
+}
+
+fn bird_position(app: &mut App) -> Mut<'_, Interpolated> {
+    app.world
+        .query_filtered::<&mut Interpolated, With<Bird>>()
+        .single_mut(&mut app.world)
+}
+
+/// Let the bird fall until the restart button can be clicked.
+fn crash(app: &mut App) {
+    advance_until(app, |app| state(app) == GameState::Over);
+    advance_until(app, restart_button_shown);
+}
+
+#[test]
+fn a_run_goes_from_ready_to_over_and_back() {
+    let mut app = app();
+
+    tap_space(&mut app);
+    assert_eq!(state(&app), GameState::Playing);
+
+    crash(&mut app);
+    assert_eq!(state(&app), GameState::Over);
+
+    tap_space(&mut app);
+    assert_eq!(state(&app), GameState::Ready);
+}
+
+#[test]
+fn hitting_the_ground_emits_death_and_collision() {
+    let mut app = app();
+
+    tap_space(&mut app);
+    assert_eq!(sent(&app, |_: &DeathEvent| true), 0);
+
+    bird_position(&mut app).current.y = -SCREEN_HEIGHT;
+    app.update();
+
+    assert_eq!(sent(&app, |_: &DeathEvent| true), 1);
+    assert_eq!(
+        sent(&app, |CollisionEvent(obstacle)| *obstacle
+            == Obstacle::Ground),
+        1
+    );
+}
+
+#[test]
+fn passing_a_pipe_pair_scores_once() {
+    let mut app = app();
+
+    tap_space(&mut app);
+
+    // Centers of the first pair are on both sides of its gap.
+    let mut pipes: Vec<Vec3> = app
+        .world
+        .query_filtered::<&Interpolated, With<Pipe>>()
+        .iter(&app.world)
+        .map(|position| position.current)
+        .collect();
+    pipes.sort_by(|a, b| a.x.total_cmp(&b.x));
+    let gap = (pipes[0].y + pipes[1].y) / 2f32;
+
+    // Long enough for the first pair to go past the bird, not the second one.
+    for _ in 0..450 {
+        let mut bird = app
+            .world
+            .query::<(&mut Bird, &mut Interpolated)>()
+            .single_mut(&mut app.world);
+
+        bird.0.velocity = 0f32;
+        bird.1.current.y = gap;
+
+        app.update();
+    }
+
+    assert_eq!(state(&app), GameState::Playing);
+    assert_eq!(app.world.resource::<Score>().current, 1);
+}
+
+#[test]
+fn leaving_game_over_cleans_up_the_run() {
+    let mut app = app();
+    let entities = app.world.entities().len();
+
+    tap_space(&mut app);
+    crash(&mut app);
+    tap_space(&mut app);
+    assert_eq!(state(&app), GameState::Ready);
+
+    assert_eq!(count::<Bird>(&mut app), 1);
+    assert_eq!(count::<Pipe>(&mut app), 0);
+    assert_eq!(count::<Medal>(&mut app), 0);
+    assert_eq!(count::<Scoreboard>(&mut app), 0);
+    assert_eq!(count::<GameOverText>(&mut app), 0);
+    assert_eq!(count::<RestartButton>(&mut app), 0);
+    assert_eq!(app.world.entities().len(), entities);
+}
+
+#[test]
+fn an_idle_ready_screen_plays_a_demo_until_a_key_is_pressed() {
+    let mut app = app();
+
+    advance_until(&mut app, |app| state(app) == GameState::Playing);
+    assert!(app.world.resource::<Autopilot>().demo);
+
+    tap_space(&mut app);
+    advance_until(&mut app, |app| state(app) == GameState::Ready);
+    assert!(!app.world.resource::<Autopilot>().demo);
+}
+
+#[test]
+fn crashing_without_flapping_unlocks_an_achievement() {
+    let mut app = app();
+
+    tap_space(&mut app);
+    crash(&mut app);
+
+    assert!(app.world.resource::<Unlocked>().0.contains("rock"));
+    assert!(!app.world.resource::<Unlocked>().0.contains("first_flight"));
+}
+
+#[test]
+fn crashing_into_a_pipe_earns_the_medal_achievements() {
+    let mut app = app();
+
+    tap_space(&mut app);
+    app.world.resource_mut::<Score>().current = 10;
+
+    let bird = bird_position(&mut app).current;
+    let mut pipes = app.world.query_filtered::<&mut Interpolated, With<Pipe>>();
+    pipes.iter_mut(&mut app.world).next().unwrap().current = bird;
+    advance_until(&mut app, |app| state(app) == GameState::Over);
+
+    assert_eq!(sent(&app, |_: &DeathEvent| true), 0);
+    assert!(app.world.resource::<Unlocked>().0.contains("shiny"));
+}
+
+#[test]
+fn a_run_the_bot_flew_for_a_while_is_not_recorded() {
+    let mut app = app();
+
+    tap_space(&mut app);
+    tap(&mut app, KeyCode::KeyA, Key::Character("a".into()));
+    advance_until(&mut app, |app| app.world.resource::<Score>().current > 0);
+    tap(&mut app, KeyCode::KeyA, Key::Character("a".into()));
+    assert!(!app.world.resource::<Autopilot>().is_flying());
+
+    crash(&mut app);
+    assert!(!app.world.contains_resource::<NameEntry>());
+}
+
+#[test]
+fn touches_start_a_run_and_restart_it_from_the_button() {
+    let mut app = app();
+
+    touch(&mut app, Vec2::new(20f32, 20f32));
+    assert_eq!(state(&app), GameState::Playing);
+
+    crash(&mut app);
+
+    // Away from the restart button.
+    touch(&mut app, Vec2::new(20f32, 20f32));
+    assert_eq!(state(&app), GameState::Over);
+
+    touch(&mut app, Vec2::new(84f32, 290f32));
+    assert_eq!(state(&app), GameState::Ready);
+}
+
+/// Let a run worth a place on the leaderboard end, until its name is asked.
+fn set_a_record(app: &mut App) {
+    app.world.resource_mut::<Score>().current = 1;
+    advance_until(app, |app| app.world.contains_resource::<NameEntry>());
+}
+
+fn recorded(app: &App) -> Vec<String> {
+    app.world
+        .resource::<Leaderboard>()
+        .table(Preset::default())
+        .map(|entry| entry.name.clone())
+        .collect()
+}
+
+#[test]
+fn a_record_is_named_with_a_touch() {
+    let mut app = app();
+
+    touch(&mut app, Vec2::new(20f32, 20f32));
+    set_a_record(&mut app);
+    touch(&mut app, Vec2::new(20f32, 20f32));
+    advance_until(&mut app, restart_button_shown);
+
+    assert_eq!(recorded(&app), [NameEntry::DEFAULT]);
+}
+
+#[test]
+fn a_record_is_named_with_a_gamepad() {
+    let mut app = app();
+
+    press(&mut app, GamepadButtonType::South);
+    assert_eq!(state(&app), GameState::Playing);
+
+    set_a_record(&mut app);
+    press(&mut app, GamepadButtonType::South);
+    advance_until(&mut app, restart_button_shown);
+
+    assert_eq!(recorded(&app), [NameEntry::DEFAULT]);
+}
+
+#[test]
+fn the_menu_button_opens_the_menu() {
+    let mut app = app();
+
+    tap_space(&mut app);
+    crash(&mut app);
+
+    touch(&mut app, Vec2::new(204f32, 290f32));
+    assert_eq!(state(&app), GameState::Menu);
+}
+
+#[test]
+fn buttons_are_focused_with_the_arrows_and_pressed_with_enter() {
+    let mut app = app();
+
+    tap_space(&mut app);
+    crash(&mut app);
+
+    // The restart button is on the left, the menu one on the right.
+    tap(&mut app, KeyCode::ArrowRight, Key::ArrowRight);
+    tap(&mut app, KeyCode::ArrowRight, Key::ArrowRight);
+    assert_eq!(state(&app), GameState::Over);
+
+    let menu = app
+        .world
+        .query_filtered::<&Interaction, With<MenuButton>>()
+        .single(&app.world);
+    assert_eq!(*menu, Interaction::Hovered);
+
+    tap(&mut app, KeyCode::Enter, Key::Enter);
+    app.update();
+    assert_eq!(state(&app), GameState::Menu);
+}